@@ -1,9 +1,12 @@
 use serde::{Deserialize, Serialize};
 use macros::serde_auto;
 
-use crate::element::{
-    AxisLabel, AxisLine, AxisPointer, AxisTick, AxisType, BoundaryGap, MinorSplitLine, MinorTick,
-    SplitArea, SplitLine,
+use crate::{
+    datatype::CompositeValue,
+    element::{
+        AxisLabel, AxisLine, AxisPointer, AxisTick, AxisType, BoundaryGap, MinorSplitLine,
+        MinorTick, SplitArea, SplitLine,
+    },
 };
 
 /// The angle axis in Polar Coordinate.
@@ -16,6 +19,8 @@ pub struct AngleAxis {
     /// Component ID.
     id: Option<String>,
 
+    show: Option<bool>,
+
     /// The index of angle axis in Polar Coordinate.
     polar_index: Option<f64>,
 
@@ -34,10 +39,10 @@ pub struct AngleAxis {
     z: Option<f64>,
 
     /// The minimun value of axis.
-    min: Option<f64>,
+    min: Option<CompositeValue>,
 
     /// The maximum value of axis.
-    max: Option<f64>,
+    max: Option<CompositeValue>,
 
     scale: Option<bool>,
 
@@ -79,6 +84,7 @@ impl AngleAxis {
         Self {
             boundary_gap: None,
             id: None,
+            show: None,
             polar_index: None,
             start_angle: None,
             clockwise: None,
@@ -117,6 +123,11 @@ impl AngleAxis {
         self
     }
 
+    pub fn show(mut self, show: bool) -> Self {
+        self.show = Some(show);
+        self
+    }
+
     pub fn polar_index<F: Into<f64>>(mut self, polar_index: F) -> Self {
         self.polar_index = Some(polar_index.into());
         self
@@ -147,12 +158,12 @@ impl AngleAxis {
         self
     }
 
-    pub fn min<F: Into<f64>>(mut self, min: F) -> Self {
+    pub fn min<C: Into<CompositeValue>>(mut self, min: C) -> Self {
         self.min = Some(min.into());
         self
     }
 
-    pub fn max<F: Into<f64>>(mut self, max: F) -> Self {
+    pub fn max<C: Into<CompositeValue>>(mut self, max: C) -> Self {
         self.max = Some(max.into());
         self
     }
@@ -242,3 +253,17 @@ impl AngleAxis {
         self
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn angle_axis() {
+        let axis = AngleAxis::new().start_angle(90).min(0.5f32).max(360u16);
+        assert_eq!(
+            serde_json::to_value(&axis).unwrap(),
+            serde_json::json!({ "startAngle": 90.0, "min": 0.5, "max": 360 })
+        );
+    }
+}
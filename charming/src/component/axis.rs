@@ -43,6 +43,7 @@ pub struct Axis {
     name_gap: Option<f64>,
 
     /// Rotation of axis name
+    #[serde(rename = "nameRotate")]
     name_rotation: Option<f64>,
 
     /// Set this to `true` to invert the axis.
@@ -280,3 +281,17 @@ impl Axis {
         self
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn axis_name_rotation() {
+        let axis = Axis::new().name("Day").name_rotation(30);
+        assert_eq!(
+            serde_json::to_value(&axis).unwrap(),
+            serde_json::json!({ "name": "Day", "nameRotate": 30.0 })
+        );
+    }
+}
@@ -3,7 +3,7 @@ use macros::serde_auto;
 
 use crate::{
     datatype::CompositeValue,
-    element::{Blur, Emphasis, ItemStyle, Label, Select},
+    element::{Blur, Emphasis, ItemStyle, Label, Select, SelectedMode, Tooltip},
 };
 
 #[serde_auto]
@@ -30,7 +30,7 @@ pub struct Geo {
 
     name_property: Option<String>,
 
-    selected_mode: Option<bool>,
+    selected_mode: Option<SelectedMode>,
 
     label: Option<Label>,
 
@@ -61,6 +61,8 @@ pub struct Geo {
     layout_size: Option<String>,
 
     silent: Option<bool>,
+
+    tooltip: Option<Tooltip>,
 }
 
 impl Geo {
@@ -91,6 +93,7 @@ impl Geo {
             layout_center: None,
             layout_size: None,
             silent: None,
+            tooltip: None,
         }
     }
 
@@ -147,8 +150,8 @@ impl Geo {
         self
     }
 
-    pub fn selected_mode(mut self, selected_mode: bool) -> Self {
-        self.selected_mode = Some(selected_mode);
+    pub fn selected_mode<S: Into<SelectedMode>>(mut self, selected_mode: S) -> Self {
+        self.selected_mode = Some(selected_mode.into());
         self
     }
 
@@ -221,4 +224,9 @@ impl Geo {
         self.silent = Some(silent);
         self
     }
+
+    pub fn tooltip<T: Into<Tooltip>>(mut self, tooltip: T) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
 }
@@ -3,120 +3,12 @@ use macros::serde_auto;
 
 use crate::{
     datatype::CompositeValue,
-    element::{Color, Padding, TextStyle, Trigger},
+    element::{Color, Tooltip},
 };
 
-#[serde_auto]
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct GridTooltip {
-    /// Whether to show the tooltip component.
-    show: Option<bool>,
-
-    /// Type of triggering.
-    trigger: Option<Trigger>,
-
-    /// The position of the tooltip's floating layer, which would follow the
-    /// position of mouse by default.
-    position: Option<(String, String)>,
-
-    /// The content formatter of tooltip's floating layer.
-    formatter: Option<String>,
-
-    /// The value formatter of tooltip's floating layer.
-    value_formatter: Option<String>,
-
-    /// The background color of tooltip's floating layer.
-    background_color: Option<Color>,
-
-    /// The border color of tooltip's floating layer.
-    border_color: Option<Color>,
-
-    /// The border width of tooltip's floating layer.
-    border_width: Option<f64>,
-
-    /// The floating layer of tooltip space around content.
-    padding: Option<Padding>,
-
-    /// Text style of tooltip's floating layer.
-    text_style: Option<TextStyle>,
-
-    /// Extra CSS style for the tooltip's floating layer.
-    extra_css_text: Option<String>,
-}
-
-impl GridTooltip {
-    pub fn new() -> Self {
-        Self {
-            show: None,
-            trigger: None,
-            position: None,
-            formatter: None,
-            value_formatter: None,
-            background_color: None,
-            border_color: None,
-            border_width: None,
-            padding: None,
-            text_style: None,
-            extra_css_text: None,
-        }
-    }
-
-    pub fn show(mut self, show: bool) -> Self {
-        self.show = Some(show);
-        self
-    }
-
-    pub fn trigger<T: Into<Trigger>>(mut self, trigger: T) -> Self {
-        self.trigger = Some(trigger.into());
-        self
-    }
-
-    pub fn position<S: Into<String>>(mut self, position: (S, S)) -> Self {
-        self.position = Some((position.0.into(), position.1.into()));
-        self
-    }
-
-    pub fn formatter<S: Into<String>>(mut self, formatter: S) -> Self {
-        self.formatter = Some(formatter.into());
-        self
-    }
-
-    pub fn value_formatter<S: Into<String>>(mut self, value_formatter: S) -> Self {
-        self.value_formatter = Some(value_formatter.into());
-        self
-    }
-
-    pub fn background_color<C: Into<Color>>(mut self, background_color: C) -> Self {
-        self.background_color = Some(background_color.into());
-        self
-    }
-
-    pub fn border_color<C: Into<Color>>(mut self, border_color: C) -> Self {
-        self.border_color = Some(border_color.into());
-        self
-    }
-
-    pub fn border_width<F: Into<f64>>(mut self, border_width: F) -> Self {
-        self.border_width = Some(border_width.into());
-        self
-    }
-
-    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
-        self.padding = Some(padding.into());
-        self
-    }
-
-    pub fn text_style<T: Into<TextStyle>>(mut self, text_style: T) -> Self {
-        self.text_style = Some(text_style.into());
-        self
-    }
-
-    pub fn extra_css_text<S: Into<String>>(mut self, extra_css_text: S) -> Self {
-        self.extra_css_text = Some(extra_css_text.into());
-        self
-    }
-}
+/// Tooltip settings in the grid.
+#[deprecated(note = "use `Tooltip` instead")]
+pub type GridTooltip = Tooltip;
 
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -177,7 +69,7 @@ pub struct Grid {
     shadow_offset_y: Option<f64>,
 
     /// Tooltip settings in the grid.
-    tooltip: Option<GridTooltip>,
+    tooltip: Option<Tooltip>,
 }
 
 impl Grid {
@@ -290,7 +182,7 @@ impl Grid {
         self
     }
 
-    pub fn tooltip<T: Into<GridTooltip>>(mut self, tooltip: T) -> Self {
+    pub fn tooltip<T: Into<Tooltip>>(mut self, tooltip: T) -> Self {
         self.tooltip = Some(tooltip.into());
         self
     }
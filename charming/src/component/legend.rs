@@ -132,6 +132,8 @@ pub struct Legend {
     /// Legend line style.
     line_style: Option<LineStyle>,
 
+    /// Legend text style. Its `rich` styles can be referenced from the
+    /// formatter as `{name|text}`.
     text_style: Option<TextStyle>,
 
     /// Rotation of the symbol.
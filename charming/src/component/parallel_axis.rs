@@ -1,18 +1,81 @@
 use serde::{Deserialize, Serialize};
 use macros::serde_auto;
 
-use crate::element::{AxisType, NameLocation};
+use crate::{
+    datatype::CompositeValue,
+    element::{
+        AxisLabel, AxisLine, AxisTick, AxisType, BoundaryGap, Color, MinorTick, NameLocation,
+        TextStyle,
+    },
+};
+
+/// Style of the brushed range selected on a parallel axis.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AreaSelectStyle {
+    width: Option<f64>,
+
+    border_width: Option<f64>,
+
+    border_color: Option<Color>,
+
+    color: Option<Color>,
+
+    opacity: Option<f64>,
+}
+
+impl AreaSelectStyle {
+    pub fn new() -> Self {
+        Self {
+            width: None,
+            border_width: None,
+            border_color: None,
+            color: None,
+            opacity: None,
+        }
+    }
+
+    pub fn width<F: Into<f64>>(mut self, width: F) -> Self {
+        self.width = Some(width.into());
+        self
+    }
+
+    pub fn border_width<F: Into<f64>>(mut self, border_width: F) -> Self {
+        self.border_width = Some(border_width.into());
+        self
+    }
+
+    pub fn border_color<C: Into<Color>>(mut self, border_color: C) -> Self {
+        self.border_color = Some(border_color.into());
+        self
+    }
+
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    pub fn opacity<F: Into<f64>>(mut self, opacity: F) -> Self {
+        self.opacity = Some(opacity.into());
+        self
+    }
+}
 
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ParallelAxis {
+    id: Option<String>,
+
     dim: Option<f64>,
 
     parallel_index: Option<f64>,
 
     realtime: Option<bool>,
 
+    area_select_style: Option<AreaSelectStyle>,
+
     #[serde(rename = "type")]
     type_: Option<AxisType>,
 
@@ -20,14 +83,43 @@ pub struct ParallelAxis {
 
     name_location: Option<NameLocation>,
 
+    name_text_style: Option<TextStyle>,
+
     name_gap: Option<f64>,
 
+    name_rotate: Option<f64>,
+
     inverse: Option<bool>,
 
-    max: Option<f64>,
+    boundary_gap: Option<BoundaryGap>,
+
+    max: Option<CompositeValue>,
+
+    min: Option<CompositeValue>,
+
+    scale: Option<bool>,
+
+    split_number: Option<f64>,
+
+    min_interval: Option<f64>,
 
-    min: Option<f64>,
+    max_interval: Option<f64>,
 
+    interval: Option<f64>,
+
+    log_base: Option<f64>,
+
+    silent: Option<bool>,
+
+    trigger_event: Option<bool>,
+
+    axis_line: Option<AxisLine>,
+
+    axis_tick: Option<AxisTick>,
+
+    minor_tick: Option<MinorTick>,
+
+    axis_label: Option<AxisLabel>,
 
     data: Vec<String>,
 }
@@ -35,20 +127,42 @@ pub struct ParallelAxis {
 impl ParallelAxis {
     pub fn new() -> Self {
         Self {
+            id: None,
             dim: None,
             parallel_index: None,
             realtime: None,
+            area_select_style: None,
             type_: None,
             name: None,
             name_location: None,
+            name_text_style: None,
             name_gap: None,
+            name_rotate: None,
             inverse: None,
+            boundary_gap: None,
             max: None,
             min: None,
+            scale: None,
+            split_number: None,
+            min_interval: None,
+            max_interval: None,
+            interval: None,
+            log_base: None,
+            silent: None,
+            trigger_event: None,
+            axis_line: None,
+            axis_tick: None,
+            minor_tick: None,
+            axis_label: None,
             data: vec![],
         }
     }
 
+    pub fn id<S: Into<String>>(mut self, id: S) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     pub fn dim<F: Into<f64>>(mut self, dim: F) -> Self {
         self.dim = Some(dim.into());
         self
@@ -64,6 +178,11 @@ impl ParallelAxis {
         self
     }
 
+    pub fn area_select_style<A: Into<AreaSelectStyle>>(mut self, area_select_style: A) -> Self {
+        self.area_select_style = Some(area_select_style.into());
+        self
+    }
+
     pub fn type_<S: Into<AxisType>>(mut self, type_: S) -> Self {
         self.type_ = Some(type_.into());
         self
@@ -79,28 +198,130 @@ impl ParallelAxis {
         self
     }
 
+    pub fn name_text_style<T: Into<TextStyle>>(mut self, name_text_style: T) -> Self {
+        self.name_text_style = Some(name_text_style.into());
+        self
+    }
+
     pub fn name_gap<F: Into<f64>>(mut self, name_gap: F) -> Self {
         self.name_gap = Some(name_gap.into());
         self
     }
 
+    pub fn name_rotate<F: Into<f64>>(mut self, name_rotate: F) -> Self {
+        self.name_rotate = Some(name_rotate.into());
+        self
+    }
+
     pub fn inverse(mut self, inverse: bool) -> Self {
         self.inverse = Some(inverse);
         self
     }
 
-    pub fn max<F: Into<f64>>(mut self, max: F) -> Self {
+    pub fn boundary_gap<B: Into<BoundaryGap>>(mut self, boundary_gap: B) -> Self {
+        self.boundary_gap = Some(boundary_gap.into());
+        self
+    }
+
+    pub fn max<C: Into<CompositeValue>>(mut self, max: C) -> Self {
         self.max = Some(max.into());
         self
     }
 
-    pub fn min<F: Into<f64>>(mut self, min: F) -> Self {
+    pub fn min<C: Into<CompositeValue>>(mut self, min: C) -> Self {
         self.min = Some(min.into());
         self
     }
 
+    pub fn scale(mut self, scale: bool) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    pub fn split_number<F: Into<f64>>(mut self, split_number: F) -> Self {
+        self.split_number = Some(split_number.into());
+        self
+    }
+
+    pub fn min_interval<F: Into<f64>>(mut self, min_interval: F) -> Self {
+        self.min_interval = Some(min_interval.into());
+        self
+    }
+
+    pub fn max_interval<F: Into<f64>>(mut self, max_interval: F) -> Self {
+        self.max_interval = Some(max_interval.into());
+        self
+    }
+
+    pub fn interval<F: Into<f64>>(mut self, interval: F) -> Self {
+        self.interval = Some(interval.into());
+        self
+    }
+
+    pub fn log_base<F: Into<f64>>(mut self, log_base: F) -> Self {
+        self.log_base = Some(log_base.into());
+        self
+    }
+
+    pub fn silent(mut self, silent: bool) -> Self {
+        self.silent = Some(silent);
+        self
+    }
+
+    pub fn trigger_event(mut self, trigger_event: bool) -> Self {
+        self.trigger_event = Some(trigger_event);
+        self
+    }
+
+    pub fn axis_line<L: Into<AxisLine>>(mut self, axis_line: L) -> Self {
+        self.axis_line = Some(axis_line.into());
+        self
+    }
+
+    pub fn axis_tick<T: Into<AxisTick>>(mut self, axis_tick: T) -> Self {
+        self.axis_tick = Some(axis_tick.into());
+        self
+    }
+
+    pub fn minor_tick<T: Into<MinorTick>>(mut self, minor_tick: T) -> Self {
+        self.minor_tick = Some(minor_tick.into());
+        self
+    }
+
+    pub fn axis_label<L: Into<AxisLabel>>(mut self, axis_label: L) -> Self {
+        self.axis_label = Some(axis_label.into());
+        self
+    }
+
     pub fn data<S: Into<String>>(mut self, data: Vec<S>) -> Self {
         self.data = data.into_iter().map(|s| s.into()).collect();
         self
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parallel_axis() {
+        let axis = ParallelAxis::new()
+            .dim(0)
+            .type_(AxisType::Log)
+            .log_base(2)
+            .min(1u32)
+            .max(1024i64)
+            .area_select_style(AreaSelectStyle::new().width(20).opacity(0.3));
+        assert_eq!(
+            serde_json::to_value(&axis).unwrap(),
+            serde_json::json!({
+                "dim": 0.0,
+                "type": "log",
+                "logBase": 2.0,
+                "min": 1,
+                "max": 1024,
+                "areaSelectStyle": { "width": 20.0, "opacity": 0.3 },
+            })
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use macros::serde_auto;
 
-use crate::datatype::CompositeValue;
+use crate::{datatype::CompositeValue, element::Tooltip};
 
 /// Polar coordinate can be used in scatter and line chart.
 #[serde_auto]
@@ -19,6 +19,8 @@ pub struct PolarCoordinate {
     center: Option<CompositeValue>,
 
     radius: Option<CompositeValue>,
+
+    tooltip: Option<Tooltip>,
 }
 
 impl PolarCoordinate {
@@ -29,6 +31,7 @@ impl PolarCoordinate {
             z: None,
             center: None,
             radius: None,
+            tooltip: None,
         }
     }
 
@@ -56,4 +59,9 @@ impl PolarCoordinate {
         self.radius = Some(radius.into());
         self
     }
+
+    pub fn tooltip<T: Into<Tooltip>>(mut self, tooltip: T) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
 }
@@ -1,7 +1,13 @@
 use serde::{Deserialize, Serialize};
 use macros::serde_auto;
 
-use crate::element::{AxisLabel, AxisLine, AxisType, BoundaryGap, NameLocation, TextStyle};
+use crate::{
+    datatype::CompositeValue,
+    element::{
+        AxisLabel, AxisLine, AxisPointer, AxisTick, AxisType, BoundaryGap, MinorSplitLine,
+        MinorTick, NameLocation, SplitArea, SplitLine, TextStyle,
+    },
+};
 
 /// Radius axis in polar coordinate.
 #[serde_auto]
@@ -12,6 +18,10 @@ pub struct RadiusAxis {
 
     polar_index: Option<f64>,
 
+    zlevel: Option<f64>,
+
+    z: Option<f64>,
+
     type_: Option<AxisType>,
 
     name: Option<String>,
@@ -22,15 +32,16 @@ pub struct RadiusAxis {
 
     name_gap: Option<f64>,
 
+    #[serde(rename = "nameRotate")]
     name_rotation: Option<f64>,
 
     inverse: Option<bool>,
 
     boundary_gap: Option<BoundaryGap>,
 
-    min: Option<f64>,
+    min: Option<CompositeValue>,
 
-    max: Option<f64>,
+    max: Option<CompositeValue>,
 
     scale: Option<bool>,
 
@@ -44,10 +55,26 @@ pub struct RadiusAxis {
 
     log_base: Option<f64>,
 
+    silent: Option<bool>,
+
+    trigger_event: Option<bool>,
+
     axis_label: Option<AxisLabel>,
 
     axis_line: Option<AxisLine>,
 
+    axis_tick: Option<AxisTick>,
+
+    minor_tick: Option<MinorTick>,
+
+    split_line: Option<SplitLine>,
+
+    minor_split_line: Option<MinorSplitLine>,
+
+    split_area: Option<SplitArea>,
+
+    axis_pointer: Option<AxisPointer>,
+
 
     data: Vec<String>,
 }
@@ -57,6 +84,8 @@ impl RadiusAxis {
         Self {
             id: None,
             polar_index: None,
+            zlevel: None,
+            z: None,
             type_: None,
             name: None,
             name_location: None,
@@ -73,8 +102,16 @@ impl RadiusAxis {
             max_interval: None,
             interval: None,
             log_base: None,
+            silent: None,
+            trigger_event: None,
             axis_label: None,
             axis_line: None,
+            axis_tick: None,
+            minor_tick: None,
+            split_line: None,
+            minor_split_line: None,
+            split_area: None,
+            axis_pointer: None,
             data: vec![],
         }
     }
@@ -89,6 +126,16 @@ impl RadiusAxis {
         self
     }
 
+    pub fn zlevel<F: Into<f64>>(mut self, zlevel: F) -> Self {
+        self.zlevel = Some(zlevel.into());
+        self
+    }
+
+    pub fn z<F: Into<f64>>(mut self, z: F) -> Self {
+        self.z = Some(z.into());
+        self
+    }
+
     pub fn type_<T: Into<AxisType>>(mut self, type_: T) -> Self {
         self.type_ = Some(type_.into());
         self
@@ -129,12 +176,12 @@ impl RadiusAxis {
         self
     }
 
-    pub fn min<F: Into<f64>>(mut self, min: F) -> Self {
+    pub fn min<C: Into<CompositeValue>>(mut self, min: C) -> Self {
         self.min = Some(min.into());
         self
     }
 
-    pub fn max<F: Into<f64>>(mut self, max: F) -> Self {
+    pub fn max<C: Into<CompositeValue>>(mut self, max: C) -> Self {
         self.max = Some(max.into());
         self
     }
@@ -169,6 +216,16 @@ impl RadiusAxis {
         self
     }
 
+    pub fn silent(mut self, silent: bool) -> Self {
+        self.silent = Some(silent);
+        self
+    }
+
+    pub fn trigger_event(mut self, trigger_event: bool) -> Self {
+        self.trigger_event = Some(trigger_event);
+        self
+    }
+
     pub fn axis_label<A: Into<AxisLabel>>(mut self, axis_label: A) -> Self {
         self.axis_label = Some(axis_label.into());
         self
@@ -179,8 +236,56 @@ impl RadiusAxis {
         self
     }
 
+    pub fn axis_tick<T: Into<AxisTick>>(mut self, axis_tick: T) -> Self {
+        self.axis_tick = Some(axis_tick.into());
+        self
+    }
+
+    pub fn minor_tick<T: Into<MinorTick>>(mut self, minor_tick: T) -> Self {
+        self.minor_tick = Some(minor_tick.into());
+        self
+    }
+
+    pub fn split_line<L: Into<SplitLine>>(mut self, split_line: L) -> Self {
+        self.split_line = Some(split_line.into());
+        self
+    }
+
+    pub fn minor_split_line<L: Into<MinorSplitLine>>(mut self, minor_split_line: L) -> Self {
+        self.minor_split_line = Some(minor_split_line.into());
+        self
+    }
+
+    pub fn split_area<A: Into<SplitArea>>(mut self, split_area: A) -> Self {
+        self.split_area = Some(split_area.into());
+        self
+    }
+
+    pub fn axis_pointer<P: Into<AxisPointer>>(mut self, axis_pointer: P) -> Self {
+        self.axis_pointer = Some(axis_pointer.into());
+        self
+    }
+
     pub fn data<S: Into<String>>(mut self, data: Vec<S>) -> Self {
         self.data = data.into_iter().map(|s| s.into()).collect();
         self
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn radius_axis() {
+        let axis = RadiusAxis::new()
+            .name("Radius")
+            .name_rotation(45)
+            .min(0)
+            .max(10usize);
+        assert_eq!(
+            serde_json::to_value(&axis).unwrap(),
+            serde_json::json!({ "name": "Radius", "nameRotate": 45.0, "min": 0, "max": 10 })
+        );
+    }
+}
@@ -1,27 +1,42 @@
 use serde::{Deserialize, Serialize};
 use macros::serde_auto;
 
-use crate::{datatype::CompositeValue, element::Orient};
+use crate::{
+    datatype::CompositeValue,
+    element::{
+        AxisLabel, AxisLine, AxisPointer, AxisTick, AxisType, BoundaryGap, MinorSplitLine,
+        MinorTick, NameLocation, Orient, SplitArea, SplitLine, TextStyle, Tooltip,
+    },
+};
 
-#[serde_auto]
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum Type {
-    Value,
-    Category,
-    Time,
-    Log,
-}
+/// Type of a single axis.
+#[deprecated(note = "use `AxisType` instead")]
+pub type Type = AxisType;
 
+/// A single axis, used by theme river and single-axis scatter charts.
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SingleAxis {
+    id: Option<String>,
+
+    zlevel: Option<f64>,
+
+    z: Option<f64>,
+
     #[serde(rename = "type")]
-    type_: Option<Type>,
+    type_: Option<AxisType>,
 
     name: Option<String>,
 
+    name_location: Option<NameLocation>,
+
+    name_text_style: Option<TextStyle>,
+
+    name_gap: Option<f64>,
+
+    name_rotate: Option<f64>,
+
     left: Option<CompositeValue>,
 
     top: Option<CompositeValue>,
@@ -38,16 +53,61 @@ pub struct SingleAxis {
 
     inverse: Option<bool>,
 
-    min: Option<String>,
+    boundary_gap: Option<BoundaryGap>,
+
+    min: Option<CompositeValue>,
+
+    max: Option<CompositeValue>,
+
+    scale: Option<bool>,
+
+    split_number: Option<f64>,
+
+    min_interval: Option<f64>,
+
+    max_interval: Option<f64>,
+
+    interval: Option<f64>,
+
+    log_base: Option<f64>,
+
+    silent: Option<bool>,
+
+    trigger_event: Option<bool>,
+
+    axis_line: Option<AxisLine>,
+
+    axis_tick: Option<AxisTick>,
+
+    minor_tick: Option<MinorTick>,
+
+    axis_label: Option<AxisLabel>,
+
+    split_line: Option<SplitLine>,
+
+    minor_split_line: Option<MinorSplitLine>,
+
+    split_area: Option<SplitArea>,
+
+    axis_pointer: Option<AxisPointer>,
 
-    max: Option<String>,
+    tooltip: Option<Tooltip>,
+
+    data: Vec<String>,
 }
 
 impl SingleAxis {
     pub fn new() -> Self {
         Self {
+            id: None,
+            zlevel: None,
+            z: None,
             type_: None,
             name: None,
+            name_location: None,
+            name_text_style: None,
+            name_gap: None,
+            name_rotate: None,
             left: None,
             top: None,
             right: None,
@@ -56,13 +116,47 @@ impl SingleAxis {
             height: None,
             orient: None,
             inverse: None,
+            boundary_gap: None,
             min: None,
             max: None,
+            scale: None,
+            split_number: None,
+            min_interval: None,
+            max_interval: None,
+            interval: None,
+            log_base: None,
+            silent: None,
+            trigger_event: None,
+            axis_line: None,
+            axis_tick: None,
+            minor_tick: None,
+            axis_label: None,
+            split_line: None,
+            minor_split_line: None,
+            split_area: None,
+            axis_pointer: None,
+            tooltip: None,
+            data: vec![],
         }
     }
 
-    pub fn type_(mut self, type_: Type) -> Self {
-        self.type_ = Some(type_);
+    pub fn id<S: Into<String>>(mut self, id: S) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn zlevel<F: Into<f64>>(mut self, zlevel: F) -> Self {
+        self.zlevel = Some(zlevel.into());
+        self
+    }
+
+    pub fn z<F: Into<f64>>(mut self, z: F) -> Self {
+        self.z = Some(z.into());
+        self
+    }
+
+    pub fn type_<T: Into<AxisType>>(mut self, type_: T) -> Self {
+        self.type_ = Some(type_.into());
         self
     }
 
@@ -71,6 +165,26 @@ impl SingleAxis {
         self
     }
 
+    pub fn name_location<E: Into<NameLocation>>(mut self, name_location: E) -> Self {
+        self.name_location = Some(name_location.into());
+        self
+    }
+
+    pub fn name_text_style<T: Into<TextStyle>>(mut self, name_text_style: T) -> Self {
+        self.name_text_style = Some(name_text_style.into());
+        self
+    }
+
+    pub fn name_gap<F: Into<f64>>(mut self, name_gap: F) -> Self {
+        self.name_gap = Some(name_gap.into());
+        self
+    }
+
+    pub fn name_rotate<F: Into<f64>>(mut self, name_rotate: F) -> Self {
+        self.name_rotate = Some(name_rotate.into());
+        self
+    }
+
     pub fn left<C: Into<CompositeValue>>(mut self, left: C) -> Self {
         self.left = Some(left.into());
         self
@@ -111,13 +225,136 @@ impl SingleAxis {
         self
     }
 
-    pub fn min<S: Into<String>>(mut self, min: S) -> Self {
+    pub fn boundary_gap<B: Into<BoundaryGap>>(mut self, boundary_gap: B) -> Self {
+        self.boundary_gap = Some(boundary_gap.into());
+        self
+    }
+
+    /// The minimum value of the axis, either a number or `"dataMin"`.
+    pub fn min<C: Into<CompositeValue>>(mut self, min: C) -> Self {
         self.min = Some(min.into());
         self
     }
 
-    pub fn max<S: Into<String>>(mut self, max: S) -> Self {
+    /// The maximum value of the axis, either a number or `"dataMax"`.
+    pub fn max<C: Into<CompositeValue>>(mut self, max: C) -> Self {
         self.max = Some(max.into());
         self
     }
+
+    pub fn scale(mut self, scale: bool) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    pub fn split_number<F: Into<f64>>(mut self, split_number: F) -> Self {
+        self.split_number = Some(split_number.into());
+        self
+    }
+
+    pub fn min_interval<F: Into<f64>>(mut self, min_interval: F) -> Self {
+        self.min_interval = Some(min_interval.into());
+        self
+    }
+
+    pub fn max_interval<F: Into<f64>>(mut self, max_interval: F) -> Self {
+        self.max_interval = Some(max_interval.into());
+        self
+    }
+
+    pub fn interval<F: Into<f64>>(mut self, interval: F) -> Self {
+        self.interval = Some(interval.into());
+        self
+    }
+
+    pub fn log_base<F: Into<f64>>(mut self, log_base: F) -> Self {
+        self.log_base = Some(log_base.into());
+        self
+    }
+
+    pub fn silent(mut self, silent: bool) -> Self {
+        self.silent = Some(silent);
+        self
+    }
+
+    pub fn trigger_event(mut self, trigger_event: bool) -> Self {
+        self.trigger_event = Some(trigger_event);
+        self
+    }
+
+    pub fn axis_line<L: Into<AxisLine>>(mut self, axis_line: L) -> Self {
+        self.axis_line = Some(axis_line.into());
+        self
+    }
+
+    pub fn axis_tick<T: Into<AxisTick>>(mut self, axis_tick: T) -> Self {
+        self.axis_tick = Some(axis_tick.into());
+        self
+    }
+
+    pub fn minor_tick<T: Into<MinorTick>>(mut self, minor_tick: T) -> Self {
+        self.minor_tick = Some(minor_tick.into());
+        self
+    }
+
+    pub fn axis_label<L: Into<AxisLabel>>(mut self, axis_label: L) -> Self {
+        self.axis_label = Some(axis_label.into());
+        self
+    }
+
+    pub fn split_line<L: Into<SplitLine>>(mut self, split_line: L) -> Self {
+        self.split_line = Some(split_line.into());
+        self
+    }
+
+    pub fn minor_split_line<L: Into<MinorSplitLine>>(mut self, minor_split_line: L) -> Self {
+        self.minor_split_line = Some(minor_split_line.into());
+        self
+    }
+
+    pub fn split_area<A: Into<SplitArea>>(mut self, split_area: A) -> Self {
+        self.split_area = Some(split_area.into());
+        self
+    }
+
+    pub fn axis_pointer<P: Into<AxisPointer>>(mut self, axis_pointer: P) -> Self {
+        self.axis_pointer = Some(axis_pointer.into());
+        self
+    }
+
+    pub fn tooltip<T: Into<Tooltip>>(mut self, tooltip: T) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Category names, for axes of type `category`.
+    pub fn data<S: Into<String>>(mut self, data: Vec<S>) -> Self {
+        self.data = data.into_iter().map(|s| s.into()).collect();
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn single_axis_styled_like_axis() {
+        let axis = SingleAxis::new()
+            .type_(AxisType::Time)
+            .max("dataMax")
+            .axis_label(AxisLabel::new().color("#999"))
+            .split_line(SplitLine::new().show(true))
+            .axis_pointer(AxisPointer::new().snap(true));
+        assert_eq!(
+            serde_json::to_value(&axis).unwrap(),
+            serde_json::json!({
+                "type": "time",
+                "max": "dataMax",
+                "axisLabel": { "color": "#999" },
+                "splitLine": { "show": true },
+                "axisPointer": { "snap": true },
+            })
+        );
+    }
 }
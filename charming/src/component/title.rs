@@ -26,7 +26,8 @@ pub struct Title {
     /// Open the hyper link of main title in specified target.
     target: Option<LinkTarget>,
 
-    /// The text style of main title.
+    /// The text style of main title. Its `rich` styles can be referenced from
+    /// the text as `{name|text}`.
     text_style: Option<TextStyle>,
 
     /// The sub title text, supporting for `\n` for newlines.
@@ -38,7 +39,7 @@ pub struct Title {
     /// Open the hyper link of sub title in specified target.
     subtarget: Option<LinkTarget>,
 
-    /// The text style of sub title.
+    /// The text style of sub title, including `rich` styles.
     subtext_style: Option<TextStyle>,
 
     /// The horizontal align of the component.
@@ -1,7 +1,37 @@
-use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use serde::{
+    de::{MapAccess, Visitor},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
 use macros::serde_auto;
 
-use crate::{datatype::CompositeValue, element::Orient};
+use crate::{
+    datatype::CompositeValue,
+    element::{Color, ItemStyle, Orient, RawString, Tooltip},
+};
+
+use super::FilterMode;
+
+/// Style of a toolbox icon while it is hovered.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolboxEmphasis {
+    icon_style: Option<ItemStyle>,
+}
+
+impl ToolboxEmphasis {
+    pub fn new() -> Self {
+        Self { icon_style: None }
+    }
+
+    pub fn icon_style<S: Into<ItemStyle>>(mut self, icon_style: S) -> Self {
+        self.icon_style = Some(icon_style.into());
+        self
+    }
+}
 
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -24,6 +54,20 @@ pub struct SaveAsImage {
     name: Option<String>,
 
     background_color: Option<String>,
+
+    connected_background_color: Option<Color>,
+
+    exclude_components: Vec<String>,
+
+    title: Option<String>,
+
+    icon: Option<String>,
+
+    icon_style: Option<ItemStyle>,
+
+    emphasis: Option<ToolboxEmphasis>,
+
+    pixel_ratio: Option<f64>,
 }
 
 impl SaveAsImage {
@@ -33,6 +77,13 @@ impl SaveAsImage {
             type_: None,
             name: None,
             background_color: None,
+            connected_background_color: None,
+            exclude_components: vec![],
+            title: None,
+            icon: None,
+            icon_style: None,
+            emphasis: None,
+            pixel_ratio: None,
         }
     }
 
@@ -55,6 +106,47 @@ impl SaveAsImage {
         self.background_color = Some(background_color.into());
         self
     }
+
+    /// Background color of the gaps between connected charts.
+    pub fn connected_background_color<C: Into<Color>>(
+        mut self,
+        connected_background_color: C,
+    ) -> Self {
+        self.connected_background_color = Some(connected_background_color.into());
+        self
+    }
+
+    /// Components left out of the saved image, e.g. `"toolbox"`.
+    pub fn exclude_components<S: Into<String>>(mut self, exclude_components: Vec<S>) -> Self {
+        self.exclude_components = exclude_components.into_iter().map(|s| s.into()).collect();
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, title: S) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn icon<S: Into<String>>(mut self, icon: S) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    pub fn icon_style<S: Into<ItemStyle>>(mut self, icon_style: S) -> Self {
+        self.icon_style = Some(icon_style.into());
+        self
+    }
+
+    pub fn emphasis<E: Into<ToolboxEmphasis>>(mut self, emphasis: E) -> Self {
+        self.emphasis = Some(emphasis.into());
+        self
+    }
+
+    /// Resolution of the saved image relative to the container size.
+    pub fn pixel_ratio<F: Into<f64>>(mut self, pixel_ratio: F) -> Self {
+        self.pixel_ratio = Some(pixel_ratio.into());
+        self
+    }
 }
 
 #[serde_auto]
@@ -64,6 +156,12 @@ pub struct Restore {
     show: Option<bool>,
 
     title: Option<String>,
+
+    icon: Option<String>,
+
+    icon_style: Option<ItemStyle>,
+
+    emphasis: Option<ToolboxEmphasis>,
 }
 
 impl Restore {
@@ -71,6 +169,9 @@ impl Restore {
         Self {
             show: None,
             title: None,
+            icon: None,
+            icon_style: None,
+            emphasis: None,
         }
     }
 
@@ -83,6 +184,21 @@ impl Restore {
         self.title = Some(title.into());
         self
     }
+
+    pub fn icon<S: Into<String>>(mut self, icon: S) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    pub fn icon_style<S: Into<ItemStyle>>(mut self, icon_style: S) -> Self {
+        self.icon_style = Some(icon_style.into());
+        self
+    }
+
+    pub fn emphasis<E: Into<ToolboxEmphasis>>(mut self, emphasis: E) -> Self {
+        self.emphasis = Some(emphasis.into());
+        self
+    }
 }
 
 #[serde_auto]
@@ -94,6 +210,30 @@ pub struct DataView {
     title: Option<String>,
 
     read_only: Option<bool>,
+
+    icon: Option<String>,
+
+    icon_style: Option<ItemStyle>,
+
+    emphasis: Option<ToolboxEmphasis>,
+
+    option_to_content: Option<RawString>,
+
+    content_to_option: Option<RawString>,
+
+    lang: Vec<String>,
+
+    background_color: Option<Color>,
+
+    text_area_color: Option<Color>,
+
+    text_area_border_color: Option<Color>,
+
+    text_color: Option<Color>,
+
+    button_color: Option<Color>,
+
+    button_text_color: Option<Color>,
 }
 
 impl DataView {
@@ -102,6 +242,18 @@ impl DataView {
             show: None,
             title: None,
             read_only: None,
+            icon: None,
+            icon_style: None,
+            emphasis: None,
+            option_to_content: None,
+            content_to_option: None,
+            lang: vec![],
+            background_color: None,
+            text_area_color: None,
+            text_area_border_color: None,
+            text_color: None,
+            button_color: None,
+            button_text_color: None,
         }
     }
 
@@ -119,6 +271,72 @@ impl DataView {
         self.read_only = Some(read_only);
         self
     }
+
+    pub fn icon<S: Into<String>>(mut self, icon: S) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    pub fn icon_style<S: Into<ItemStyle>>(mut self, icon_style: S) -> Self {
+        self.icon_style = Some(icon_style.into());
+        self
+    }
+
+    pub fn emphasis<E: Into<ToolboxEmphasis>>(mut self, emphasis: E) -> Self {
+        self.emphasis = Some(emphasis.into());
+        self
+    }
+
+    /// JavaScript function `(option) => string | HTMLElement` rendering the
+    /// data view content, replacing the default text area.
+    pub fn option_to_content<S: Into<RawString>>(mut self, option_to_content: S) -> Self {
+        self.option_to_content = Some(option_to_content.into());
+        self
+    }
+
+    /// JavaScript function `(container, option) => option` reading the
+    /// edited content back into an option when the view is not read only.
+    pub fn content_to_option<S: Into<RawString>>(mut self, content_to_option: S) -> Self {
+        self.content_to_option = Some(content_to_option.into());
+        self
+    }
+
+    /// Texts of the data view: the title, the close button and the refresh
+    /// button, in that order.
+    pub fn lang<S: Into<String>>(mut self, lang: Vec<S>) -> Self {
+        self.lang = lang.into_iter().map(|s| s.into()).collect();
+        self
+    }
+
+    pub fn background_color<C: Into<Color>>(mut self, background_color: C) -> Self {
+        self.background_color = Some(background_color.into());
+        self
+    }
+
+    pub fn text_area_color<C: Into<Color>>(mut self, text_area_color: C) -> Self {
+        self.text_area_color = Some(text_area_color.into());
+        self
+    }
+
+    pub fn text_area_border_color<C: Into<Color>>(mut self, text_area_border_color: C) -> Self {
+        self.text_area_border_color = Some(text_area_border_color.into());
+        self
+    }
+
+    pub fn text_color<C: Into<Color>>(mut self, text_color: C) -> Self {
+        self.text_color = Some(text_color.into());
+        self
+    }
+
+    pub fn button_color<C: Into<Color>>(mut self, button_color: C) -> Self {
+        self.button_color = Some(button_color.into());
+        self
+    }
+
+    pub fn button_text_color<C: Into<Color>>(mut self, button_text_color: C) -> Self {
+        self.button_text_color = Some(button_text_color.into());
+        self
+    }
 }
 
 #[serde_auto]
@@ -133,6 +351,16 @@ pub enum MagicTypeType {
     Stack,
 }
 
+impl MagicTypeType {
+    fn key(&self) -> &'static str {
+        match self {
+            Self::Line => "line",
+            Self::Bar => "bar",
+            Self::Stack => "stack",
+        }
+    }
+}
+
 impl From<&str> for MagicTypeType {
     fn from(s: &str) -> Self {
         match s {
@@ -148,19 +376,36 @@ impl From<&str> for MagicTypeType {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MagicType {
+    show: Option<bool>,
+
     type_: Option<Vec<MagicTypeType>>,
 
     title: Option<String>,
+
+    icon_style: Option<ItemStyle>,
+
+    emphasis: Option<ToolboxEmphasis>,
+
+    series_index: Option<BTreeMap<String, Vec<f64>>>,
 }
 
 impl MagicType {
     pub fn new() -> Self {
         Self {
+            show: None,
             type_: None,
             title: None,
+            icon_style: None,
+            emphasis: None,
+            series_index: None,
         }
     }
 
+    pub fn show(mut self, show: bool) -> Self {
+        self.show = Some(show);
+        self
+    }
+
     pub fn type_(mut self, type_: Vec<MagicTypeType>) -> Self {
         self.type_ = Some(type_);
         self
@@ -170,6 +415,29 @@ impl MagicType {
         self.title = Some(title.into());
         self
     }
+
+    pub fn icon_style<S: Into<ItemStyle>>(mut self, icon_style: S) -> Self {
+        self.icon_style = Some(icon_style.into());
+        self
+    }
+
+    pub fn emphasis<E: Into<ToolboxEmphasis>>(mut self, emphasis: E) -> Self {
+        self.emphasis = Some(emphasis.into());
+        self
+    }
+
+    /// Restrict switching to the given series indices for a chart type.
+    pub fn series_index<F: Into<f64>>(
+        mut self,
+        type_: MagicTypeType,
+        series_index: Vec<F>,
+    ) -> Self {
+        self.series_index.get_or_insert_with(BTreeMap::new).insert(
+            type_.key().to_string(),
+            series_index.into_iter().map(|f| f.into()).collect(),
+        );
+        self
+    }
 }
 
 #[serde_auto]
@@ -184,41 +452,243 @@ pub enum BrushType {
     Clear,
 }
 
+impl BrushType {
+    fn key(&self) -> &'static str {
+        match self {
+            Self::Rect => "rect",
+            Self::Polygon => "polygon",
+            Self::LineX => "lineX",
+            Self::LineY => "lineY",
+            Self::Keep => "keep",
+            Self::Clear => "clear",
+        }
+    }
+}
+
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Brush {
-
     type_: Vec<BrushType>,
+
+    icon: Option<BTreeMap<String, String>>,
+
+    title: Option<BTreeMap<String, String>>,
 }
 
 impl Brush {
     pub fn new() -> Self {
-        Self { type_: vec![] }
+        Self {
+            type_: vec![],
+            icon: None,
+            title: None,
+        }
     }
 
     pub fn type_(mut self, type_: Vec<BrushType>) -> Self {
         self.type_ = type_;
         self
     }
+
+    /// Icon of the button for one brush type.
+    pub fn icon<S: Into<String>>(mut self, type_: BrushType, icon: S) -> Self {
+        self.icon
+            .get_or_insert_with(BTreeMap::new)
+            .insert(type_.key().to_string(), icon.into());
+        self
+    }
+
+    /// Title of the button for one brush type.
+    pub fn title<S: Into<String>>(mut self, type_: BrushType, title: S) -> Self {
+        self.title
+            .get_or_insert_with(BTreeMap::new)
+            .insert(type_.key().to_string(), title.into());
+        self
+    }
+}
+
+/// Texts or icons of the zoom and the zoom reset buttons.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolboxDataZoomText {
+    zoom: Option<String>,
+
+    back: Option<String>,
 }
 
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ToolboxDataZoom {
+    show: Option<bool>,
+
+    title: Option<ToolboxDataZoomText>,
+
+    icon: Option<ToolboxDataZoomText>,
+
+    icon_style: Option<ItemStyle>,
+
+    emphasis: Option<ToolboxEmphasis>,
+
+    filter_mode: Option<FilterMode>,
+
+    x_axis_index: Option<CompositeValue>,
+
     y_axis_index: Option<CompositeValue>,
+
+    x_axis_id: Option<CompositeValue>,
+
+    y_axis_id: Option<CompositeValue>,
+
+    brush_style: Option<ItemStyle>,
 }
 
 impl ToolboxDataZoom {
     pub fn new() -> Self {
-        Self { y_axis_index: None }
+        Self {
+            show: None,
+            title: None,
+            icon: None,
+            icon_style: None,
+            emphasis: None,
+            filter_mode: None,
+            x_axis_index: None,
+            y_axis_index: None,
+            x_axis_id: None,
+            y_axis_id: None,
+            brush_style: None,
+        }
+    }
+
+    pub fn show(mut self, show: bool) -> Self {
+        self.show = Some(show);
+        self
     }
 
+    /// Titles of the zoom and the zoom reset buttons.
+    pub fn title<S: Into<String>>(mut self, zoom: S, back: S) -> Self {
+        self.title = Some(ToolboxDataZoomText {
+            zoom: Some(zoom.into()),
+            back: Some(back.into()),
+        });
+        self
+    }
+
+    /// Icons of the zoom and the zoom reset buttons.
+    pub fn icon<S: Into<String>>(mut self, zoom: S, back: S) -> Self {
+        self.icon = Some(ToolboxDataZoomText {
+            zoom: Some(zoom.into()),
+            back: Some(back.into()),
+        });
+        self
+    }
+
+    pub fn icon_style<S: Into<ItemStyle>>(mut self, icon_style: S) -> Self {
+        self.icon_style = Some(icon_style.into());
+        self
+    }
+
+    pub fn emphasis<E: Into<ToolboxEmphasis>>(mut self, emphasis: E) -> Self {
+        self.emphasis = Some(emphasis.into());
+        self
+    }
+
+    pub fn filter_mode<F: Into<FilterMode>>(mut self, filter_mode: F) -> Self {
+        self.filter_mode = Some(filter_mode.into());
+        self
+    }
+
+    /// The x axes controlled by the zoom, or `"none"` to leave x axes alone.
+    pub fn x_axis_index<C: Into<CompositeValue>>(mut self, x_axis_index: C) -> Self {
+        self.x_axis_index = Some(x_axis_index.into());
+        self
+    }
+
+    /// The y axes controlled by the zoom, or `"none"` to leave y axes alone.
     pub fn y_axis_index<C: Into<CompositeValue>>(mut self, y_axis_index: C) -> Self {
         self.y_axis_index = Some(y_axis_index.into());
         self
     }
+
+    pub fn x_axis_id<C: Into<CompositeValue>>(mut self, x_axis_id: C) -> Self {
+        self.x_axis_id = Some(x_axis_id.into());
+        self
+    }
+
+    pub fn y_axis_id<C: Into<CompositeValue>>(mut self, y_axis_id: C) -> Self {
+        self.y_axis_id = Some(y_axis_id.into());
+        self
+    }
+
+    /// Style of the selection rectangle while zooming.
+    pub fn brush_style<S: Into<ItemStyle>>(mut self, brush_style: S) -> Self {
+        self.brush_style = Some(brush_style.into());
+        self
+    }
+}
+
+/// A user defined toolbox button.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolboxCustomTool {
+    show: Option<bool>,
+
+    title: Option<String>,
+
+    icon: Option<String>,
+
+    icon_style: Option<ItemStyle>,
+
+    emphasis: Option<ToolboxEmphasis>,
+
+    onclick: Option<RawString>,
+}
+
+impl ToolboxCustomTool {
+    pub fn new() -> Self {
+        Self {
+            show: None,
+            title: None,
+            icon: None,
+            icon_style: None,
+            emphasis: None,
+            onclick: None,
+        }
+    }
+
+    pub fn show(mut self, show: bool) -> Self {
+        self.show = Some(show);
+        self
+    }
+
+    pub fn title<S: Into<String>>(mut self, title: S) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Icon of the button, e.g. `"path://..."` or `"image://..."`.
+    pub fn icon<S: Into<String>>(mut self, icon: S) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    pub fn icon_style<S: Into<ItemStyle>>(mut self, icon_style: S) -> Self {
+        self.icon_style = Some(icon_style.into());
+        self
+    }
+
+    pub fn emphasis<E: Into<ToolboxEmphasis>>(mut self, emphasis: E) -> Self {
+        self.emphasis = Some(emphasis.into());
+        self
+    }
+
+    /// JavaScript function called when the button is clicked.
+    pub fn onclick<S: Into<RawString>>(mut self, onclick: S) -> Self {
+        self.onclick = Some(onclick.into());
+        self
+    }
 }
 
 #[serde_auto]
@@ -236,6 +706,9 @@ pub struct Feature {
     data_zoom: Option<ToolboxDataZoom>,
 
     brush: Option<Brush>,
+
+    #[serde(flatten)]
+    custom_tools: ToolboxCustomTools,
 }
 
 impl Feature {
@@ -247,6 +720,7 @@ impl Feature {
             magic_type: None,
             data_zoom: None,
             brush: None,
+            custom_tools: ToolboxCustomTools(vec![]),
         }
     }
 
@@ -279,18 +753,96 @@ impl Feature {
         self.brush = Some(brush);
         self
     }
+
+    /// Add a user defined button. ECharts requires the name of a custom
+    /// tool to start with `my`, so it is prepended when missing, e.g.
+    /// `export` becomes `myExport`.
+    pub fn custom_tool<S: Into<String>>(mut self, name: S, tool: ToolboxCustomTool) -> Self {
+        let name = name.into();
+        let name = if name.starts_with("my") {
+            name
+        } else {
+            let mut chars = name.chars();
+            match chars.next() {
+                Some(first) => format!("my{}{}", first.to_uppercase(), chars.as_str()),
+                None => "my".to_string(),
+            }
+        };
+        match self.custom_tools.0.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, existing)) => *existing = tool,
+            None => self.custom_tools.0.push((name, tool)),
+        }
+        self
+    }
+}
+
+/// User defined buttons of a [`Feature`], serialized as a map in the order
+/// they were added, which is the order ECharts shows them in.
+#[derive(Debug, Clone, PartialEq)]
+struct ToolboxCustomTools(Vec<(String, ToolboxCustomTool)>);
+
+impl Serialize for ToolboxCustomTools {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (name, tool) in &self.0 {
+            map.serialize_entry(name, tool)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for ToolboxCustomTools {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ToolsVisitor;
+
+        impl<'de> Visitor<'de> for ToolsVisitor {
+            type Value = ToolboxCustomTools;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a map of custom tools")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut tools = vec![];
+                while let Some(entry) = map.next_entry()? {
+                    tools.push(entry);
+                }
+                Ok(ToolboxCustomTools(tools))
+            }
+        }
+
+        deserializer.deserialize_map(ToolsVisitor)
+    }
 }
 
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Toolbox {
+    id: Option<String>,
+
     show: Option<bool>,
 
     feature: Option<Feature>,
 
     orient: Option<Orient>,
 
+    item_size: Option<f64>,
+
+    item_gap: Option<f64>,
+
+    show_title: Option<bool>,
+
+    icon_style: Option<ItemStyle>,
+
+    emphasis: Option<ToolboxEmphasis>,
+
+    tooltip: Option<Tooltip>,
+
+    zlevel: Option<f64>,
+
+    z: Option<f64>,
+
     left: Option<CompositeValue>,
 
     top: Option<CompositeValue>,
@@ -298,21 +850,41 @@ pub struct Toolbox {
     right: Option<CompositeValue>,
 
     bottom: Option<CompositeValue>,
+
+    width: Option<CompositeValue>,
+
+    height: Option<CompositeValue>,
 }
 
 impl Toolbox {
     pub fn new() -> Self {
         Self {
+            id: None,
             show: None,
             feature: None,
             orient: None,
+            item_size: None,
+            item_gap: None,
+            show_title: None,
+            icon_style: None,
+            emphasis: None,
+            tooltip: None,
+            zlevel: None,
+            z: None,
             left: None,
             top: None,
             right: None,
             bottom: None,
+            width: None,
+            height: None,
         }
     }
 
+    pub fn id<S: Into<String>>(mut self, id: S) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     pub fn show(mut self, show: bool) -> Self {
         self.show = Some(show);
         self
@@ -328,6 +900,51 @@ impl Toolbox {
         self
     }
 
+    /// Size of the toolbox icons.
+    pub fn item_size<F: Into<f64>>(mut self, item_size: F) -> Self {
+        self.item_size = Some(item_size.into());
+        self
+    }
+
+    /// Gap between the toolbox icons.
+    pub fn item_gap<F: Into<f64>>(mut self, item_gap: F) -> Self {
+        self.item_gap = Some(item_gap.into());
+        self
+    }
+
+    /// Whether to show the title of an icon on hover.
+    pub fn show_title(mut self, show_title: bool) -> Self {
+        self.show_title = Some(show_title);
+        self
+    }
+
+    pub fn icon_style<S: Into<ItemStyle>>(mut self, icon_style: S) -> Self {
+        self.icon_style = Some(icon_style.into());
+        self
+    }
+
+    pub fn emphasis<E: Into<ToolboxEmphasis>>(mut self, emphasis: E) -> Self {
+        self.emphasis = Some(emphasis.into());
+        self
+    }
+
+    /// Tooltip of the toolbox icons, shown instead of the titles when
+    /// `show_title` is disabled.
+    pub fn tooltip<T: Into<Tooltip>>(mut self, tooltip: T) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    pub fn zlevel<F: Into<f64>>(mut self, zlevel: F) -> Self {
+        self.zlevel = Some(zlevel.into());
+        self
+    }
+
+    pub fn z<F: Into<f64>>(mut self, z: F) -> Self {
+        self.z = Some(z.into());
+        self
+    }
+
     pub fn left<C: Into<CompositeValue>>(mut self, left: C) -> Self {
         self.left = Some(left.into());
         self
@@ -348,6 +965,16 @@ impl Toolbox {
         self
     }
 
+    pub fn width<C: Into<CompositeValue>>(mut self, width: C) -> Self {
+        self.width = Some(width.into());
+        self
+    }
+
+    pub fn height<C: Into<CompositeValue>>(mut self, height: C) -> Self {
+        self.height = Some(height.into());
+        self
+    }
+
     pub fn save_as_image_type(&self) -> Option<&SaveAsImageType> {
         self.feature
             .as_ref()
@@ -355,3 +982,66 @@ impl Toolbox {
             .and_then(|s| s.type_.as_ref())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::element::process_raw_strings;
+
+    use super::*;
+
+    #[test]
+    fn toolbox_custom_tool() {
+        let toolbox = Toolbox::new().item_size(18).show_title(false).feature(
+            Feature::new()
+                .save_as_image(
+                    SaveAsImage::new()
+                        .pixel_ratio(2)
+                        .exclude_components(vec!["toolbox"]),
+                )
+                .data_zoom(
+                    ToolboxDataZoom::new()
+                        .y_axis_index("none")
+                        .title("Zoom", "Reset"),
+                )
+                .custom_tool(
+                    "myExport",
+                    ToolboxCustomTool::new()
+                        .title("Export")
+                        .icon("path://M0,0L10,10")
+                        .onclick("function () { exportData(); }"),
+                ),
+        );
+        assert_eq!(
+            process_raw_strings(&serde_json::to_string(&toolbox).unwrap()),
+            r#"{"feature":{"saveAsImage":{"excludeComponents":["toolbox"],"pixelRatio":2.0},"dataZoom":{"title":{"zoom":"Zoom","back":"Reset"},"yAxisIndex":"none"},"myExport":{"title":"Export","icon":"path://M0,0L10,10","onclick":function () { exportData(); }}},"itemSize":18.0,"showTitle":false}"#
+        );
+    }
+
+    #[test]
+    fn custom_tool_name_prefixed() {
+        let feature = Feature::new()
+            .custom_tool("export", ToolboxCustomTool::new().title("Export"))
+            .custom_tool("myReload", ToolboxCustomTool::new().title("Reload"));
+        assert_eq!(
+            serde_json::to_value(&feature).unwrap(),
+            serde_json::json!({
+                "myExport": { "title": "Export" },
+                "myReload": { "title": "Reload" },
+            })
+        );
+    }
+
+    #[test]
+    fn custom_tools_keep_insertion_order() {
+        let feature = Feature::new()
+            .custom_tool("zoomIn", ToolboxCustomTool::new().title("Zoom in"))
+            .custom_tool("export", ToolboxCustomTool::new().title("Export"))
+            .custom_tool("zoomIn", ToolboxCustomTool::new().title("Zoom"));
+        let json = serde_json::to_string(&feature).unwrap();
+        assert_eq!(
+            json,
+            r#"{"myZoomIn":{"title":"Zoom"},"myExport":{"title":"Export"}}"#
+        );
+        assert_eq!(serde_json::from_str::<Feature>(&json).unwrap(), feature);
+    }
+}
@@ -46,6 +46,22 @@ use super::DataPoint;
 /// let data: DataFrame = df![1, 1, 2, 3, 5, 7, 13];
 /// ```
 ///
+/// ## Missing Values
+///
+/// Missing values are represented by `None`. Any `Option<T>` whose inner type
+/// can be converted into a [`DataPoint`] can be used, and `None` is serialized
+/// as `null`, which breaks the line at that point unless `connect_nulls` is
+/// set on the series.
+///
+/// ```rust
+/// use charming::datatype::DataFrame;
+/// use charming::df;
+///
+/// let data: DataFrame = df![Some(820), Some(932), None::<i32>, Some(934)];
+///
+/// let data: DataFrame = df![[0, Some(3.4)], [1, None::<f64>], [2, Some(10.8)]];
+/// ```
+///
 pub type DataFrame = Vec<DataPoint>;
 
 #[macro_export]
@@ -3,12 +3,12 @@ use std::fmt::Debug;
 use serde::{Deserialize, Serialize};
 use macros::serde_auto;
 
-use crate::element::ItemStyle;
+use crate::element::{AreaStyle, ItemStyle, Label, LineStyle, Symbol};
 
 use super::CompositeValue;
 
 #[serde_auto]
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DataPointItem {
     value: CompositeValue,
@@ -16,6 +16,16 @@ pub struct DataPointItem {
     name: Option<String>,
 
     item_style: Option<ItemStyle>,
+
+    label: Option<Label>,
+
+    symbol: Option<Symbol>,
+
+    symbol_size: Option<f64>,
+
+    line_style: Option<LineStyle>,
+
+    area_style: Option<AreaStyle>,
 }
 
 impl DataPointItem {
@@ -24,6 +34,11 @@ impl DataPointItem {
             value: value.into(),
             name: None,
             item_style: None,
+            label: None,
+            symbol: None,
+            symbol_size: None,
+            line_style: None,
+            area_style: None,
         }
     }
 
@@ -36,6 +51,35 @@ impl DataPointItem {
         self.item_style = Some(item_style.into());
         self
     }
+
+    pub fn label<L: Into<Label>>(mut self, label: L) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn symbol<S: Into<Symbol>>(mut self, symbol: S) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+
+    pub fn symbol_size<F: Into<f64>>(mut self, symbol_size: F) -> Self {
+        self.symbol_size = Some(symbol_size.into());
+        self
+    }
+
+    /// Line style of this item, used by series drawing a line per item such
+    /// as `Radar`.
+    pub fn line_style<L: Into<LineStyle>>(mut self, line_style: L) -> Self {
+        self.line_style = Some(line_style.into());
+        self
+    }
+
+    /// Area style of this item, used by series drawing an area per item such
+    /// as `Radar`.
+    pub fn area_style<A: Into<AreaStyle>>(mut self, area_style: A) -> Self {
+        self.area_style = Some(area_style.into());
+        self
+    }
 }
 
 impl Debug for DataPointItem {
@@ -47,12 +91,6 @@ impl Debug for DataPointItem {
     }
 }
 
-impl PartialEq for DataPointItem {
-    fn eq(&self, other: &Self) -> bool {
-        self.value == other.value && self.name == other.name
-    }
-}
-
 impl<V> From<V> for DataPointItem
 where
     V: Into<CompositeValue>,
@@ -77,7 +115,17 @@ where
 #[serde(untagged)]
 pub enum DataPoint {
     Value(CompositeValue),
-    Item(DataPointItem),
+    Item(Box<DataPointItem>),
+}
+
+impl DataPoint {
+    /// Value of the point, dropping the name and styles of an item.
+    pub(crate) fn into_value(self) -> CompositeValue {
+        match self {
+            DataPoint::Value(value) => value,
+            DataPoint::Item(item) => item.value,
+        }
+    }
 }
 
 impl<V> From<V> for DataPoint
@@ -95,13 +143,13 @@ where
     S: Into<String>,
 {
     fn from(v: (V, S)) -> Self {
-        DataPoint::Item(DataPointItem::new(v.0).name(v.1))
+        DataPoint::Item(Box::new(DataPointItem::new(v.0).name(v.1)))
     }
 }
 
 impl From<DataPointItem> for DataPoint {
     fn from(item: DataPointItem) -> Self {
-        DataPoint::Item(item)
+        DataPoint::Item(Box::new(item))
     }
 }
 
@@ -150,10 +198,29 @@ mod test {
         assert_eq!(p, q);
     }
 
+    #[test]
+    fn data_point_from_option() {
+        let p: DataPoint = Some(42).into();
+        let q = DataPoint::Value(42.into());
+        assert_eq!(p, q);
+
+        let p: DataPoint = None::<f64>.into();
+        let q = DataPoint::Value(CompositeValue::Null);
+        assert_eq!(p, q);
+        assert_eq!(serde_json::to_string(&p).unwrap(), "null");
+    }
+
     #[test]
     fn data_point_from_tuple() {
         let p: DataPoint = (42, "foo").into();
-        let q = DataPoint::Item((42, "foo").into());
+        let q = DataPoint::Item(Box::new((42, "foo").into()));
         assert_eq!(p, q);
     }
+
+    #[test]
+    fn data_point_items_differing_in_style() {
+        let p = DataPointItem::new(42).name("foo");
+        let q = DataPointItem::new(42).name("foo").symbol_size(8);
+        assert_ne!(p, q);
+    }
 }
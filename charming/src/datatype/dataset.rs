@@ -1,9 +1,9 @@
 use serde::{ser::SerializeSeq, Deserialize, Serialize};
 use macros::serde_auto;
 
-use crate::element::RawString;
-
-use super::{DataSource, Dimension};
+#[cfg(any(feature = "arrow", feature = "polars"))]
+use super::record::Columns;
+use super::{DataSource, DataTransform, Dimension};
 
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -13,6 +13,10 @@ pub struct Source {
     #[serde(rename(serialize = "source"))]
     json: Option<serde_json::Value>,
 
+    #[cfg(any(feature = "arrow", feature = "polars"))]
+    #[serde(rename(serialize = "source"), skip_deserializing)]
+    columns: Option<Columns>,
+
     id: Option<String>,
 
     dimensions: Vec<Dimension>,
@@ -24,6 +28,8 @@ impl Source {
             id: None,
             source: Some(source),
             json: None,
+            #[cfg(any(feature = "arrow", feature = "polars"))]
+            columns: None,
             dimensions: vec![],
         }
     }
@@ -33,6 +39,8 @@ impl Source {
             id: None,
             source: None,
             json: Some(source),
+            #[cfg(any(feature = "arrow", feature = "polars"))]
+            columns: None,
             dimensions: vec![],
         }
     }
@@ -42,6 +50,8 @@ impl Source {
             id: Some(id),
             source: Some(source),
             json: None,
+            #[cfg(any(feature = "arrow", feature = "polars"))]
+            columns: None,
             dimensions: vec![],
         }
     }
@@ -51,6 +61,19 @@ impl Source {
             id: Some(id),
             source: None,
             json: Some(source),
+            #[cfg(any(feature = "arrow", feature = "polars"))]
+            columns: None,
+            dimensions: vec![],
+        }
+    }
+
+    #[cfg(any(feature = "arrow", feature = "polars"))]
+    pub(crate) fn new_columns(columns: Columns) -> Self {
+        Source {
+            id: None,
+            source: None,
+            json: None,
+            columns: Some(columns),
             dimensions: vec![],
         }
     }
@@ -91,7 +114,7 @@ where
 pub struct Transform {
     id: Option<String>,
 
-    transform: Option<RawString>,
+    transform: Option<DataTransform>,
 
     from_dataset_id: Option<String>,
 
@@ -116,7 +139,7 @@ impl Transform {
         self
     }
 
-    pub fn transform<R: Into<RawString>>(mut self, transform: R) -> Self {
+    pub fn transform<D: Into<DataTransform>>(mut self, transform: D) -> Self {
         self.transform = Some(transform.into());
         self
     }
@@ -137,8 +160,11 @@ impl Transform {
     }
 }
 
-impl From<&str> for Transform {
-    fn from(transform: &str) -> Self {
+impl<D> From<D> for Transform
+where
+    D: Into<DataTransform>,
+{
+    fn from(transform: D) -> Self {
         Self::new().transform(transform)
     }
 }
@@ -3,7 +3,7 @@ use macros::serde_auto;
 
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(untagged)]
+#[serde(rename_all = "lowercase")]
 pub enum DimensionType {
     Number,
     Float,
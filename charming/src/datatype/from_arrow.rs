@@ -0,0 +1,216 @@
+use ::arrow::{
+    array::{Array, ArrayRef, AsArray},
+    compute::cast,
+    datatypes::{DataType, Float64Type, Int64Type, TimeUnit},
+    record_batch::RecordBatch,
+};
+
+use crate::EchartsError;
+
+use super::{
+    record::{source_from_columns, ColumnValues},
+    DimensionType, Source,
+};
+
+impl Source {
+    /// Creates a column-oriented [`Source`] from an Arrow [`RecordBatch`].
+    ///
+    /// Only the given columns are included, or every column of the batch if
+    /// `columns` is empty. Integer, floating point and decimal columns map to
+    /// [`DimensionType::Number`], date and timestamp columns to
+    /// [`DimensionType::Time`] (as UTC milliseconds since the Unix epoch, also
+    /// for timestamps with a time zone), and
+    /// string, boolean and dictionary columns to [`DimensionType::Ordinal`].
+    /// `UInt64` columns are converted to floating point, so that values above
+    /// `i64::MAX` are kept. Null values are kept as missing values, and
+    /// selecting the same column twice is an error.
+    ///
+    /// Series plot the columns by referring to the source through
+    /// `dataset_index` and `encode`.
+    ///
+    /// This method requires the `arrow` feature.
+    #[cfg_attr(docsrs, doc(cfg(feature = "arrow")))]
+    pub fn from_record_batch(batch: &RecordBatch, columns: &[&str]) -> Result<Self, EchartsError> {
+        source_from_columns(record_batch_columns(batch, columns)?)
+    }
+}
+
+fn record_batch_columns(
+    batch: &RecordBatch,
+    columns: &[&str],
+) -> Result<Vec<(String, DimensionType, ColumnValues)>, EchartsError> {
+    let schema = batch.schema();
+    let names: Vec<&str> = if columns.is_empty() {
+        schema.fields().iter().map(|f| f.name().as_str()).collect()
+    } else {
+        columns.to_vec()
+    };
+
+    names
+        .into_iter()
+        .map(|name| {
+            let array = batch
+                .column_by_name(name)
+                .ok_or_else(|| EchartsError::DatasetError(format!("column {} not found", name)))?;
+            let (type_, values) = array_values(name, array)?;
+            Ok((name.to_string(), type_, values))
+        })
+        .collect()
+}
+
+fn array_values(
+    name: &str,
+    array: &ArrayRef,
+) -> Result<(DimensionType, ColumnValues), EchartsError> {
+    let cast_error = |error: ::arrow::error::ArrowError| {
+        EchartsError::DatasetError(format!("column {}: {}", name, error))
+    };
+
+    match array.data_type() {
+        t if t.is_integer() && *t != DataType::UInt64 => {
+            let array = cast(array, &DataType::Int64).map_err(cast_error)?;
+            let values = array.as_primitive::<Int64Type>().iter().collect();
+            Ok((DimensionType::Number, ColumnValues::Integers(values)))
+        }
+        t if t.is_numeric() => {
+            let array = cast(array, &DataType::Float64).map_err(cast_error)?;
+            let values = array.as_primitive::<Float64Type>().iter().collect();
+            Ok((DimensionType::Number, ColumnValues::Floats(values)))
+        }
+        t @ (DataType::Date32 | DataType::Date64 | DataType::Timestamp(_, _)) => {
+            // Keeping the time zone makes the cast keep the UTC instant instead
+            // of shifting it to the local wall-clock time.
+            let time_zone = match t {
+                DataType::Timestamp(_, time_zone) => time_zone.clone(),
+                _ => None,
+            };
+            let array = cast(
+                array,
+                &DataType::Timestamp(TimeUnit::Millisecond, time_zone),
+            )
+            .and_then(|array| cast(&array, &DataType::Int64))
+            .map_err(cast_error)?;
+            let values = array.as_primitive::<Int64Type>().iter().collect();
+            Ok((DimensionType::Time, ColumnValues::Integers(values)))
+        }
+        DataType::Utf8
+        | DataType::LargeUtf8
+        | DataType::Utf8View
+        | DataType::Boolean
+        | DataType::Dictionary(_, _) => {
+            let array = cast(array, &DataType::Utf8).map_err(cast_error)?;
+            let values = array
+                .as_string::<i32>()
+                .iter()
+                .map(|v| v.map(str::to_string))
+                .collect();
+            Ok((DimensionType::Ordinal, ColumnValues::Strings(values)))
+        }
+        t => Err(EchartsError::DatasetError(format!(
+            "column {}: unsupported data type {}",
+            name, t
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use ::arrow::array::{
+        Date32Array, Float64Array, Int32Array, StringArray, TimestampSecondArray, UInt64Array,
+    };
+
+    use super::*;
+
+    fn batch() -> RecordBatch {
+        RecordBatch::try_from_iter(vec![
+            (
+                "day",
+                Arc::new(Date32Array::from(vec![19358, 19359])) as ArrayRef,
+            ),
+            (
+                "product",
+                Arc::new(StringArray::from(vec![Some("tea"), None])) as ArrayRef,
+            ),
+            (
+                "count",
+                Arc::new(Int32Array::from(vec![Some(3), None])) as ArrayRef,
+            ),
+            (
+                "price",
+                Arc::new(Float64Array::from(vec![1.5, 2.0])) as ArrayRef,
+            ),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn source_from_record_batch() {
+        let source = Source::from_record_batch(&batch(), &["day", "product", "count"]).unwrap();
+        assert_eq!(
+            serde_json::to_value(&source).unwrap(),
+            serde_json::json!({
+                "source": {
+                    "day": [1672531200000i64, 1672617600000i64],
+                    "product": ["tea", null],
+                    "count": [3, null],
+                },
+                "dimensions": [
+                    {"type": "time", "name": "day"},
+                    {"type": "ordinal", "name": "product"},
+                    {"type": "number", "name": "count"},
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn source_from_missing_column() {
+        assert!(matches!(
+            Source::from_record_batch(&batch(), &["missing"]),
+            Err(EchartsError::DatasetError(_))
+        ));
+    }
+
+    #[test]
+    fn source_from_zoned_timestamps() {
+        let batch = RecordBatch::try_from_iter(vec![(
+            "time",
+            Arc::new(TimestampSecondArray::from(vec![3600]).with_timezone("+02:00")) as ArrayRef,
+        )])
+        .unwrap();
+        let source = Source::from_record_batch(&batch, &[]).unwrap();
+        assert_eq!(
+            serde_json::to_value(&source).unwrap()["source"],
+            serde_json::json!({ "time": [3_600_000] })
+        );
+    }
+
+    #[test]
+    fn source_from_large_unsigned_integers() {
+        let batch = RecordBatch::try_from_iter(vec![(
+            "id",
+            Arc::new(UInt64Array::from(vec![Some(u64::MAX), None])) as ArrayRef,
+        )])
+        .unwrap();
+        let source = Source::from_record_batch(&batch, &[]).unwrap();
+        assert_eq!(
+            serde_json::to_value(&source).unwrap(),
+            serde_json::json!({
+                "source": { "id": [u64::MAX as f64, null] },
+                "dimensions": [{"type": "number", "name": "id"}],
+            })
+        );
+    }
+
+    #[test]
+    fn source_from_duplicate_columns() {
+        assert_eq!(
+            Source::from_record_batch(&batch(), &["count", "count"]),
+            Err(EchartsError::DatasetError(
+                "duplicate column count".to_string()
+            ))
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use ::polars::prelude::{Column, DataFrame as PolarsDataFrame, DataType, TimeUnit};
+
+use crate::EchartsError;
+
+use super::{
+    record::{source_from_columns, ColumnValues},
+    DimensionType, Source,
+};
+
+impl Source {
+    /// Creates a column-oriented [`Source`] from a Polars `DataFrame`.
+    ///
+    /// Only the given columns are included, or every column of the frame if
+    /// `columns` is empty. Integer and floating point columns map to
+    /// [`DimensionType::Number`], `Date` and `Datetime` columns to
+    /// [`DimensionType::Time`] (as UTC milliseconds since the Unix epoch, also
+    /// for datetimes with a time zone), and
+    /// string and boolean columns to [`DimensionType::Ordinal`]. `UInt64`
+    /// columns are converted to floating point, so that values above
+    /// `i64::MAX` are kept. Null values are kept as missing values, and
+    /// selecting the same column twice is an error.
+    ///
+    /// Series plot the columns by referring to the source through
+    /// `dataset_index` and `encode`.
+    ///
+    /// This method requires the `polars` feature.
+    #[cfg_attr(docsrs, doc(cfg(feature = "polars")))]
+    pub fn from_polars(df: &PolarsDataFrame, columns: &[&str]) -> Result<Self, EchartsError> {
+        source_from_columns(polars_columns(df, columns)?)
+    }
+}
+
+fn polars_columns(
+    df: &PolarsDataFrame,
+    columns: &[&str],
+) -> Result<Vec<(String, DimensionType, ColumnValues)>, EchartsError> {
+    let selected: Vec<&Column> = if columns.is_empty() {
+        df.columns().iter().collect()
+    } else {
+        columns
+            .iter()
+            .map(|name| {
+                df.column(name)
+                    .map_err(|_| EchartsError::DatasetError(format!("column {} not found", name)))
+            })
+            .collect::<Result<_, _>>()?
+    };
+
+    selected
+        .into_iter()
+        .map(|column| {
+            let (type_, values) = column_values(column)?;
+            Ok((column.name().to_string(), type_, values))
+        })
+        .collect()
+}
+
+fn column_values(column: &Column) -> Result<(DimensionType, ColumnValues), EchartsError> {
+    let name = column.name().to_string();
+    let polars_error = |error: ::polars::prelude::PolarsError| {
+        EchartsError::DatasetError(format!("column {}: {}", name, error))
+    };
+
+    match column.dtype() {
+        t if t.is_float() || *t == DataType::UInt64 => {
+            let column = column.cast(&DataType::Float64).map_err(polars_error)?;
+            let values = column.f64().map_err(polars_error)?.iter().collect();
+            Ok((DimensionType::Number, ColumnValues::Floats(values)))
+        }
+        t if t.is_integer() => {
+            let column = column.cast(&DataType::Int64).map_err(polars_error)?;
+            let values = column.i64().map_err(polars_error)?.iter().collect();
+            Ok((DimensionType::Number, ColumnValues::Integers(values)))
+        }
+        t @ (DataType::Date | DataType::Datetime(_, _)) => {
+            // Keeping the time zone keeps the UTC instant of every value.
+            let time_zone = match t {
+                DataType::Datetime(_, time_zone) => time_zone.clone(),
+                _ => None,
+            };
+            let column = column
+                .cast(&DataType::Datetime(TimeUnit::Milliseconds, time_zone))
+                .and_then(|column| column.cast(&DataType::Int64))
+                .map_err(polars_error)?;
+            let values = column.i64().map_err(polars_error)?.iter().collect();
+            Ok((DimensionType::Time, ColumnValues::Integers(values)))
+        }
+        DataType::String | DataType::Boolean => {
+            let column = column.cast(&DataType::String).map_err(polars_error)?;
+            let values = column
+                .str()
+                .map_err(polars_error)?
+                .iter()
+                .map(|v| v.map(str::to_string))
+                .collect();
+            Ok((DimensionType::Ordinal, ColumnValues::Strings(values)))
+        }
+        t => Err(EchartsError::DatasetError(format!(
+            "column {}: unsupported data type {}",
+            name, t
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ::polars::prelude::{IntoColumn, NamedFrom, Series};
+
+    use super::*;
+
+    fn frame() -> PolarsDataFrame {
+        PolarsDataFrame::new_infer_height(vec![
+            Series::new("product".into(), &[Some("tea"), None]).into_column(),
+            Series::new("count".into(), &[Some(3i32), None]).into_column(),
+            Series::new("price".into(), &[1.5f64, 2.0]).into_column(),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn source_from_polars() {
+        let source = Source::from_polars(&frame(), &[]).unwrap();
+        assert_eq!(
+            serde_json::to_value(&source).unwrap(),
+            serde_json::json!({
+                "source": {
+                    "product": ["tea", null],
+                    "count": [3, null],
+                    "price": [1.5, 2.0],
+                },
+                "dimensions": [
+                    {"type": "ordinal", "name": "product"},
+                    {"type": "number", "name": "count"},
+                    {"type": "number", "name": "price"},
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn source_from_missing_column() {
+        assert!(matches!(
+            Source::from_polars(&frame(), &["missing"]),
+            Err(EchartsError::DatasetError(_))
+        ));
+    }
+}
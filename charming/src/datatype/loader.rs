@@ -0,0 +1,331 @@
+use std::{fs::File, io::BufRead, io::BufReader, io::Read, path::Path};
+
+use serde_json::Value;
+
+use crate::EchartsError;
+
+use super::{
+    record::{source_from_rows, JsonRecord},
+    Source,
+};
+
+/// [`CsvLoader`] reads delimited text (CSV, TSV, ...) into a [`Source`].
+///
+/// The first row is a header row whose fields become the dimensions of the
+/// source. The type of every dimension is inferred from its values: numeric
+/// columns are `number`, ISO 8601 dates are `time` and everything else is
+/// `ordinal`. Empty fields are treated as missing values.
+///
+/// This loader requires the `csv` feature.
+///
+/// ```rust
+/// use charming::datatype::CsvLoader;
+///
+/// let source = CsvLoader::new()
+///     .from_str("product,2015,2016\nMatcha Latte,43.3,85.8\nMilk Tea,83.1,73.4\n")
+///     .unwrap();
+///
+/// let source = CsvLoader::tsv()
+///     .from_str("date\tvalue\n2023-01-01\t12\n2023-01-02\t\n")
+///     .unwrap();
+/// ```
+#[cfg(feature = "csv")]
+#[cfg_attr(docsrs, doc(cfg(feature = "csv")))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvLoader {
+    delimiter: u8,
+    quote: u8,
+    trim: bool,
+}
+
+#[cfg(feature = "csv")]
+impl CsvLoader {
+    pub fn new() -> Self {
+        Self {
+            delimiter: b',',
+            quote: b'"',
+            trim: true,
+        }
+    }
+
+    /// Creates a loader for tab-separated values.
+    pub fn tsv() -> Self {
+        Self::new().delimiter(b'\t')
+    }
+
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    pub fn quote(mut self, quote: u8) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    /// Whether to trim whitespace around fields, defaults to `true`.
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    pub fn from_path<P: AsRef<Path>>(&self, path: P) -> Result<Source, EchartsError> {
+        let file =
+            File::open(path).map_err(|error| EchartsError::DatasetError(error.to_string()))?;
+        self.from_reader(file)
+    }
+
+    pub fn from_str(&self, s: &str) -> Result<Source, EchartsError> {
+        self.from_reader(s.as_bytes())
+    }
+
+    pub fn from_reader<R: Read>(&self, reader: R) -> Result<Source, EchartsError> {
+        let mut reader = csv::ReaderBuilder::new()
+            .delimiter(self.delimiter)
+            .quote(self.quote)
+            .trim(if self.trim {
+                csv::Trim::All
+            } else {
+                csv::Trim::None
+            })
+            .has_headers(false)
+            .flexible(true)
+            .from_reader(reader);
+
+        let mut records = reader.records();
+        let names: Vec<String> = match records.next() {
+            Some(header) => header
+                .map_err(|error| EchartsError::DatasetError(error.to_string()))?
+                .iter()
+                .map(|name| name.to_string())
+                .collect(),
+            None => return Err(EchartsError::DatasetError("missing header row".to_string())),
+        };
+
+        let mut rows = vec![];
+        for record in records {
+            let record = record.map_err(|error| EchartsError::DatasetError(error.to_string()))?;
+            if record.len() != names.len() {
+                return Err(ragged_row_error(
+                    record.position().map_or(0, |p| p.line() as usize),
+                    record.len(),
+                    names.len(),
+                ));
+            }
+            rows.push(record.iter().map(parse_field).collect());
+        }
+
+        source_from_rows(names, rows)
+    }
+}
+
+/// [`JsonLinesLoader`] reads newline-delimited JSON into a [`Source`].
+///
+/// Every non-empty line is either a JSON object or a JSON array. For objects,
+/// the keys of the first line become the dimensions of the source and later
+/// lines are matched by key. For arrays, the first line is the header row.
+/// The type of every dimension is inferred from its values, the same way as
+/// for CSV files.
+///
+/// ```rust
+/// use charming::datatype::JsonLinesLoader;
+///
+/// let source = JsonLinesLoader::new()
+///     .from_str(r#"
+///         {"date": "2004-01-02", "open": 10452.74, "close": 10409.85}
+///         {"date": "2004-01-05", "open": 10411.85, "close": 10544.07}
+///     "#)
+///     .unwrap();
+///
+/// let source = JsonLinesLoader::new()
+///     .from_str("[\"Income\", \"Country\"]\n[815, \"Australia\"]\n[1314, \"Canada\"]\n")
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonLinesLoader {}
+
+impl JsonLinesLoader {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    pub fn from_path<P: AsRef<Path>>(&self, path: P) -> Result<Source, EchartsError> {
+        let file =
+            File::open(path).map_err(|error| EchartsError::DatasetError(error.to_string()))?;
+        self.from_reader(file)
+    }
+
+    pub fn from_str(&self, s: &str) -> Result<Source, EchartsError> {
+        self.from_reader(s.as_bytes())
+    }
+
+    pub fn from_reader<R: Read>(&self, reader: R) -> Result<Source, EchartsError> {
+        let mut names: Option<Vec<String>> = None;
+        let mut rows = vec![];
+
+        for (index, line) in BufReader::new(reader).lines().enumerate() {
+            let line = line.map_err(|error| EchartsError::DatasetError(error.to_string()))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let record: JsonRecord = serde_json::from_str(&line).map_err(|error| {
+                EchartsError::DatasetError(format!("line {}: {}", index + 1, error))
+            })?;
+
+            match (record, &names) {
+                (JsonRecord::Map(fields), None) => {
+                    let (keys, row) = fields.into_iter().unzip();
+                    names = Some(keys);
+                    rows.push(row);
+                }
+                (JsonRecord::Map(mut fields), Some(names)) => {
+                    let mut row = Vec::with_capacity(names.len());
+                    for name in names {
+                        let position = fields.iter().position(|(key, _)| key == name);
+                        row.push(position.map_or(Value::Null, |p| fields.swap_remove(p).1));
+                    }
+                    rows.push(row);
+                }
+                (JsonRecord::Array(header), None) => {
+                    names = Some(
+                        header
+                            .into_iter()
+                            .map(|name| match name {
+                                Value::String(s) => s,
+                                other => other.to_string(),
+                            })
+                            .collect(),
+                    );
+                }
+                (JsonRecord::Array(row), Some(names)) => {
+                    if row.len() != names.len() {
+                        return Err(ragged_row_error(index + 1, row.len(), names.len()));
+                    }
+                    rows.push(row);
+                }
+                (JsonRecord::Scalar, _) => {
+                    return Err(EchartsError::DatasetError(format!(
+                        "line {}: expected a JSON object or array",
+                        index + 1
+                    )))
+                }
+            }
+        }
+
+        match names {
+            Some(names) => source_from_rows(names, rows),
+            None => Err(EchartsError::DatasetError("missing header row".to_string())),
+        }
+    }
+}
+
+fn ragged_row_error(line: usize, found: usize, expected: usize) -> EchartsError {
+    EchartsError::DatasetError(format!(
+        "line {}: found {} fields, but the header has {}",
+        line, found, expected
+    ))
+}
+
+#[cfg(feature = "csv")]
+fn parse_field(field: &str) -> Value {
+    if field.is_empty() || field == "-" {
+        Value::Null
+    } else if let Ok(n) = field.parse::<i64>() {
+        Value::from(n)
+    } else if let Some(n) = field
+        .parse::<f64>()
+        .ok()
+        .and_then(serde_json::Number::from_f64)
+    {
+        Value::Number(n)
+    } else {
+        Value::String(field.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn csv_with_inferred_dimensions() {
+        let source = CsvLoader::new()
+            .from_str("date,product,amount\n2023-01-01,\"Tea, Milk\",12\n2023-01-02,Coffee,\n")
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&source).unwrap(),
+            serde_json::json!({
+                "source": [
+                    ["2023-01-01", "Tea, Milk", 12],
+                    ["2023-01-02", "Coffee", null],
+                ],
+                "dimensions": [
+                    {"type": "time", "name": "date"},
+                    {"type": "ordinal", "name": "product"},
+                    {"type": "number", "name": "amount"},
+                ],
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn csv_with_custom_delimiter() {
+        let source = CsvLoader::new()
+            .delimiter(b';')
+            .from_str("x;y\n1;2.5\n")
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&source).unwrap()["source"],
+            serde_json::json!([[1, 2.5]])
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn csv_with_ragged_rows() {
+        let error = CsvLoader::new().from_str("x,y\n1,2\n3\n").unwrap_err();
+        assert_eq!(
+            error,
+            EchartsError::DatasetError("line 3: found 1 fields, but the header has 2".to_string())
+        );
+    }
+
+    #[test]
+    fn json_lines_of_objects_and_arrays() {
+        let objects = JsonLinesLoader::new()
+            .from_str("{\"x\": 1, \"y\": \"a\"}\n\n{\"y\": \"b\", \"x\": 2}\n")
+            .unwrap();
+        let arrays = JsonLinesLoader::new()
+            .from_str("[\"x\", \"y\"]\n[1, \"a\"]\n[2, \"b\"]\n")
+            .unwrap();
+        assert_eq!(objects, arrays);
+
+        let error = JsonLinesLoader::new()
+            .from_str("[\"x\", \"y\"]\n[1]\n")
+            .unwrap_err();
+        assert_eq!(
+            error,
+            EchartsError::DatasetError("line 2: found 1 fields, but the header has 2".to_string())
+        );
+    }
+
+    #[test]
+    fn json_lines_with_duplicate_header() {
+        assert_eq!(
+            JsonLinesLoader::new().from_str("[\"x\", \"x\"]\n[1, 2]\n"),
+            Err(EchartsError::DatasetError("duplicate column x".to_string()))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn csv_with_duplicate_header() {
+        assert_eq!(
+            CsvLoader::new().from_str("x,y,x\n1,2,3\n"),
+            Err(EchartsError::DatasetError("duplicate column x".to_string()))
+        );
+    }
+}
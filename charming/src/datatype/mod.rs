@@ -2,12 +2,21 @@ pub mod dataframe;
 pub mod datapoint;
 pub mod dataset;
 pub mod dimension;
+#[cfg(feature = "arrow")]
+mod from_arrow;
+#[cfg(feature = "polars")]
+mod from_polars;
+pub mod loader;
+mod record;
 pub mod source;
+pub mod transform;
 pub mod value;
 
 pub use dataframe::*;
 pub use datapoint::*;
 pub use dataset::*;
 pub use dimension::*;
+pub use loader::*;
 pub use source::*;
+pub use transform::*;
 pub use value::*;
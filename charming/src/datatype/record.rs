@@ -0,0 +1,401 @@
+use std::fmt;
+
+use serde::{
+    de::{MapAccess, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
+use serde_json::Value;
+
+use crate::EchartsError;
+
+use super::{CompositeValue, DataSource, Dimension, DimensionType, NumericValue, Source};
+
+impl Source {
+    /// Creates a [`Source`] from a slice of serializable records, one row per
+    /// record.
+    ///
+    /// Each record must serialize into a map (e.g. a struct with named fields).
+    /// The field names of all records become the dimensions of the source, in
+    /// the order they are first serialized, and the type of every dimension
+    /// is inferred from its values: numbers are [`DimensionType::Number`],
+    /// date and datetime strings (such as those produced by `chrono`) are
+    /// [`DimensionType::Time`], and other strings, as well as columns mixing
+    /// numbers and strings, are [`DimensionType::Ordinal`]. `None` fields and
+    /// fields missing from a record become missing values.
+    ///
+    /// ```rust
+    /// use charming::datatype::Source;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Sale {
+    ///     date: String,
+    ///     product: String,
+    ///     amount: f64,
+    /// }
+    ///
+    /// let sales = vec![
+    ///     Sale { date: "2023-01-01".into(), product: "coffee".into(), amount: 43.3 },
+    ///     Sale { date: "2023-01-02".into(), product: "juice".into(), amount: 83.1 },
+    /// ];
+    /// let source = Source::from_records(&sales).unwrap();
+    /// ```
+    pub fn from_records<T: Serialize>(records: &[T]) -> Result<Self, EchartsError> {
+        let mut names: Vec<String> = vec![];
+        let mut rows: Vec<Vec<Value>> = Vec::with_capacity(records.len());
+
+        for (index, record) in records.iter().enumerate() {
+            let fields = match serde_json::to_string(record)
+                .and_then(|json| serde_json::from_str::<JsonRecord>(&json))
+            {
+                Ok(JsonRecord::Map(fields)) => fields,
+                Ok(_) => {
+                    return Err(EchartsError::DatasetError(format!(
+                        "record {} is not serialized as a map",
+                        index
+                    )))
+                }
+                Err(error) => return Err(EchartsError::DatasetError(error.to_string())),
+            };
+
+            let mut row = vec![Value::Null; names.len()];
+            for (name, value) in fields {
+                match names.iter().position(|n| *n == name) {
+                    Some(column) => row[column] = value,
+                    None => {
+                        names.push(name);
+                        row.push(value);
+                    }
+                }
+            }
+            rows.push(row);
+        }
+
+        for row in &mut rows {
+            row.resize(names.len(), Value::Null);
+        }
+        source_from_rows(names, rows)
+    }
+}
+
+/// A JSON value whose top-level map keeps its keys in the order they were
+/// written, which is the order the fields were serialized in.
+pub(crate) enum JsonRecord {
+    Map(Vec<(String, Value)>),
+    Array(Vec<Value>),
+    Scalar,
+}
+
+impl<'de> Deserialize<'de> for JsonRecord {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct JsonRecordVisitor;
+
+        impl<'de> Visitor<'de> for JsonRecordVisitor {
+            type Value = JsonRecord;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a JSON value")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<JsonRecord, A::Error> {
+                let mut fields = vec![];
+                while let Some(field) = map.next_entry()? {
+                    fields.push(field);
+                }
+                Ok(JsonRecord::Map(fields))
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<JsonRecord, A::Error> {
+                let mut values = vec![];
+                while let Some(value) = seq.next_element()? {
+                    values.push(value);
+                }
+                Ok(JsonRecord::Array(values))
+            }
+
+            fn visit_bool<E>(self, _: bool) -> Result<JsonRecord, E> {
+                Ok(JsonRecord::Scalar)
+            }
+
+            fn visit_i64<E>(self, _: i64) -> Result<JsonRecord, E> {
+                Ok(JsonRecord::Scalar)
+            }
+
+            fn visit_u64<E>(self, _: u64) -> Result<JsonRecord, E> {
+                Ok(JsonRecord::Scalar)
+            }
+
+            fn visit_f64<E>(self, _: f64) -> Result<JsonRecord, E> {
+                Ok(JsonRecord::Scalar)
+            }
+
+            fn visit_str<E>(self, _: &str) -> Result<JsonRecord, E> {
+                Ok(JsonRecord::Scalar)
+            }
+
+            fn visit_unit<E>(self) -> Result<JsonRecord, E> {
+                Ok(JsonRecord::Scalar)
+            }
+        }
+
+        deserializer.deserialize_any(JsonRecordVisitor)
+    }
+}
+
+/// Builds a [`Source`] from named columns and rows of JSON values, inferring
+/// the type of every dimension from its values. Repeated names are an
+/// error.
+pub(crate) fn source_from_rows(
+    names: Vec<String>,
+    rows: Vec<Vec<Value>>,
+) -> Result<Source, EchartsError> {
+    if let Some(name) = names
+        .iter()
+        .enumerate()
+        .find_map(|(i, name)| names[..i].contains(name).then_some(name))
+    {
+        return Err(EchartsError::DatasetError(format!(
+            "duplicate column {}",
+            name
+        )));
+    }
+
+    let dimensions = names
+        .iter()
+        .enumerate()
+        .map(|(column, name)| {
+            let dimension = Dimension::new().name(name.as_str());
+            match infer_dimension_type(rows.iter().filter_map(|row| row.get(column))) {
+                Some(type_) => dimension.type_(type_),
+                None => dimension,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let values = rows
+        .into_iter()
+        .enumerate()
+        .map(|(index, row)| {
+            row.into_iter()
+                .map(|value| composite_value(value, index))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Source::new(DataSource::Mixed(values)).dimensions(dimensions))
+}
+
+/// The values of a single column of a dataframe, kept in their own type until
+/// they are serialized.
+#[cfg(any(feature = "arrow", feature = "polars"))]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub(crate) enum ColumnValues {
+    Integers(Vec<Option<i64>>),
+    Floats(Vec<Option<f64>>),
+    Strings(Vec<Option<String>>),
+}
+
+/// Named columns, serialized as an object that maps every name to its column.
+#[cfg(any(feature = "arrow", feature = "polars"))]
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Columns(Vec<(String, ColumnValues)>);
+
+#[cfg(any(feature = "arrow", feature = "polars"))]
+impl Serialize for Columns {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (name, values) in &self.0 {
+            map.serialize_entry(name, values)?;
+        }
+        map.end()
+    }
+}
+
+/// Builds a column-oriented [`Source`], i.e. a `source` object that maps every
+/// dimension name to its column of values.
+#[cfg(any(feature = "arrow", feature = "polars"))]
+pub(crate) fn source_from_columns(
+    columns: Vec<(String, DimensionType, ColumnValues)>,
+) -> Result<Source, EchartsError> {
+    let mut dimensions = Vec::with_capacity(columns.len());
+    let mut named = Vec::with_capacity(columns.len());
+    for (name, type_, values) in columns {
+        if named.iter().any(|(n, _)| *n == name) {
+            return Err(EchartsError::DatasetError(format!(
+                "duplicate column {}",
+                name
+            )));
+        }
+        dimensions.push(Dimension::new().name(name.as_str()).type_(type_));
+        named.push((name, values));
+    }
+    Ok(Source::new_columns(Columns(named)).dimensions(dimensions))
+}
+
+/// Infers the dimension type of a column, ignoring missing values. Columns
+/// mixing values of different kinds are ordinal. Returns `None` when the
+/// column is empty or holds arrays or maps.
+pub(crate) fn infer_dimension_type<'a, I>(values: I) -> Option<DimensionType>
+where
+    I: IntoIterator<Item = &'a Value>,
+{
+    let mut inferred = None;
+    for value in values {
+        let type_ = match value {
+            Value::Null => continue,
+            Value::Number(_) => DimensionType::Number,
+            Value::String(s) if is_time(s) => DimensionType::Time,
+            Value::String(_) | Value::Bool(_) => DimensionType::Ordinal,
+            Value::Array(_) | Value::Object(_) => return None,
+        };
+        inferred = match inferred {
+            None => Some(type_),
+            Some(current) if current == type_ => Some(current),
+            Some(_) => Some(DimensionType::Ordinal),
+        };
+    }
+    inferred
+}
+
+/// Whether a string looks like an ISO 8601 date or datetime, i.e. starts with
+/// `YYYY-MM-DD`, optionally followed by a time part.
+pub(crate) fn is_time(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    if bytes.len() < 10 {
+        return false;
+    }
+    let date = bytes[..10].iter().enumerate().all(|(i, b)| match i {
+        4 | 7 => *b == b'-',
+        _ => b.is_ascii_digit(),
+    });
+    date && (bytes.len() == 10 || bytes[10] == b'T' || bytes[10] == b' ')
+}
+
+pub(crate) fn composite_value(value: Value, row: usize) -> Result<CompositeValue, EchartsError> {
+    match value {
+        Value::Null => Ok(CompositeValue::Null),
+        Value::Bool(b) => Ok(CompositeValue::String(b.to_string())),
+        Value::Number(n) => Ok(CompositeValue::Number(numeric_value(&n))),
+        Value::String(s) => Ok(CompositeValue::String(s)),
+        Value::Array(values) => values
+            .into_iter()
+            .map(|value| composite_value(value, row))
+            .collect::<Result<Vec<_>, _>>()
+            .map(CompositeValue::Array),
+        Value::Object(_) => Err(EchartsError::DatasetError(format!(
+            "row {} contains a nested map, which cannot be a dataset value",
+            row
+        ))),
+    }
+}
+
+fn numeric_value(n: &serde_json::Number) -> NumericValue {
+    match n.as_i64().map(i32::try_from) {
+        Some(Ok(i)) => NumericValue::Integer(i),
+        _ => NumericValue::Float(n.as_f64().unwrap_or(f64::NAN)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::Serialize;
+
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Reading {
+        sensor: &'static str,
+        time: &'static str,
+        value: Option<f64>,
+        count: i64,
+    }
+
+    #[test]
+    fn source_from_records() {
+        let readings = vec![
+            Reading {
+                sensor: "a",
+                time: "2023-10-01T12:00:00Z",
+                value: Some(1.5),
+                count: 3,
+            },
+            Reading {
+                sensor: "b",
+                time: "2023-10-01T12:05:00Z",
+                value: None,
+                count: 5_000_000_000,
+            },
+        ];
+        let source = Source::from_records(&readings).unwrap();
+        assert_eq!(
+            serde_json::to_value(&source).unwrap(),
+            serde_json::json!({
+                "source": [
+                    ["a", "2023-10-01T12:00:00Z", 1.5, 3],
+                    ["b", "2023-10-01T12:05:00Z", null, 5_000_000_000.0],
+                ],
+                "dimensions": [
+                    {"type": "ordinal", "name": "sensor"},
+                    {"type": "time", "name": "time"},
+                    {"type": "number", "name": "value"},
+                    {"type": "number", "name": "count"},
+                ],
+            })
+        );
+    }
+
+    #[derive(Serialize)]
+    struct Event {
+        name: &'static str,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        tag: Option<&'static str>,
+        value: Value,
+    }
+
+    #[test]
+    fn source_from_sparse_records() {
+        let events = vec![
+            Event {
+                name: "start",
+                tag: None,
+                value: Value::from(1),
+            },
+            Event {
+                name: "stop",
+                tag: Some("manual"),
+                value: Value::from("n/a"),
+            },
+        ];
+        let source = Source::from_records(&events).unwrap();
+        assert_eq!(
+            serde_json::to_value(&source).unwrap(),
+            serde_json::json!({
+                "source": [["start", 1, null], ["stop", "n/a", "manual"]],
+                "dimensions": [
+                    {"type": "ordinal", "name": "name"},
+                    {"type": "ordinal", "name": "value"},
+                    {"type": "ordinal", "name": "tag"},
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn source_from_non_map_records() {
+        assert!(matches!(
+            Source::from_records(&[1, 2, 3]),
+            Err(EchartsError::DatasetError(_))
+        ));
+    }
+
+    #[test]
+    fn time_strings() {
+        assert!(is_time("2023-10-01"));
+        assert!(is_time("2023-10-01 08:00"));
+        assert!(!is_time("2023/10/01"));
+        assert!(!is_time("Monday"));
+    }
+}
@@ -30,6 +30,26 @@ impl From<Vec<Vec<CompositeValue>>> for DataSource {
     }
 }
 
+impl From<Vec<Vec<Option<i32>>>> for DataSource {
+    fn from(v: Vec<Vec<Option<i32>>>) -> Self {
+        DataSource::Mixed(
+            v.into_iter()
+                .map(|row| row.into_iter().map(CompositeValue::from).collect())
+                .collect(),
+        )
+    }
+}
+
+impl From<Vec<Vec<Option<f64>>>> for DataSource {
+    fn from(v: Vec<Vec<Option<f64>>>) -> Self {
+        DataSource::Mixed(
+            v.into_iter()
+                .map(|row| row.into_iter().map(CompositeValue::from).collect())
+                .collect(),
+        )
+    }
+}
+
 #[macro_export]
 macro_rules! ds {
     ($([$($x:expr),* $(,)?]),* $(,)?) => {
@@ -109,4 +129,32 @@ mod test {
             ])
         );
     }
+
+    #[test]
+    fn data_frame_from_optional_floats() {
+        let ds: DataSource = vec![vec![Some(1.0), None], vec![None, Some(4.0)]].into();
+        assert_eq!(
+            ds,
+            DataSource::Mixed(vec![
+                vec![
+                    CompositeValue::Number(NumericValue::Float(1.0)),
+                    CompositeValue::Null
+                ],
+                vec![
+                    CompositeValue::Null,
+                    CompositeValue::Number(NumericValue::Float(4.0))
+                ],
+            ])
+        );
+    }
+
+    #[test]
+    fn data_frame_from_mixed_with_nulls() {
+        let missing: Option<i32> = None;
+        let ds = ds!([1, missing, "Monday"], [Some(2.5), 3, None::<&str>]);
+        assert_eq!(
+            serde_json::to_string(&ds).unwrap(),
+            r#"[[1,null,"Monday"],[2.5,3,null]]"#
+        );
+    }
 }
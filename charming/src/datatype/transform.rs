@@ -0,0 +1,939 @@
+use std::cmp::Ordering;
+
+use serde::{Deserialize, Serialize};
+use macros::serde_auto;
+
+use crate::{element::RawString, EchartsError};
+
+use super::{CompositeValue, NumericValue};
+
+/// A dimension referenced by a transform, either by its name or its index.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TransformDimension {
+    Index(i32),
+    Name(String),
+}
+
+impl From<i32> for TransformDimension {
+    fn from(index: i32) -> Self {
+        TransformDimension::Index(index)
+    }
+}
+
+impl From<&str> for TransformDimension {
+    fn from(name: &str) -> Self {
+        TransformDimension::Name(name.to_string())
+    }
+}
+
+impl From<String> for TransformDimension {
+    fn from(name: String) -> Self {
+        TransformDimension::Name(name)
+    }
+}
+
+/// How values are parsed before they are compared.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransformParser {
+    Time,
+    Trim,
+    Number,
+}
+
+/// A comparison on a single dimension of a `filter` transform. Several
+/// operators on the same comparison must all hold.
+///
+/// ```rust
+/// use charming::datatype::Comparison;
+///
+/// let since_1950 = Comparison::new("Year").gte(1950);
+/// let in_fifties = Comparison::new("Year").gte(1950).lt(1960);
+/// ```
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Comparison {
+    dimension: TransformDimension,
+
+    #[serde(rename = "=", alias = "eq")]
+    eq: Option<CompositeValue>,
+
+    #[serde(rename = "!=", alias = "ne")]
+    ne: Option<CompositeValue>,
+
+    #[serde(rename = "<", alias = "lt")]
+    lt: Option<CompositeValue>,
+
+    #[serde(rename = "<=", alias = "lte")]
+    lte: Option<CompositeValue>,
+
+    #[serde(rename = ">", alias = "gt")]
+    gt: Option<CompositeValue>,
+
+    #[serde(rename = ">=", alias = "gte")]
+    gte: Option<CompositeValue>,
+
+    reg: Option<String>,
+
+    parser: Option<TransformParser>,
+}
+
+impl Comparison {
+    pub fn new<D: Into<TransformDimension>>(dimension: D) -> Self {
+        Self {
+            dimension: dimension.into(),
+            eq: None,
+            ne: None,
+            lt: None,
+            lte: None,
+            gt: None,
+            gte: None,
+            reg: None,
+            parser: None,
+        }
+    }
+
+    pub fn eq<C: Into<CompositeValue>>(mut self, eq: C) -> Self {
+        self.eq = Some(eq.into());
+        self
+    }
+
+    pub fn ne<C: Into<CompositeValue>>(mut self, ne: C) -> Self {
+        self.ne = Some(ne.into());
+        self
+    }
+
+    pub fn lt<C: Into<CompositeValue>>(mut self, lt: C) -> Self {
+        self.lt = Some(lt.into());
+        self
+    }
+
+    pub fn lte<C: Into<CompositeValue>>(mut self, lte: C) -> Self {
+        self.lte = Some(lte.into());
+        self
+    }
+
+    pub fn gt<C: Into<CompositeValue>>(mut self, gt: C) -> Self {
+        self.gt = Some(gt.into());
+        self
+    }
+
+    pub fn gte<C: Into<CompositeValue>>(mut self, gte: C) -> Self {
+        self.gte = Some(gte.into());
+        self
+    }
+
+    /// A regular expression the value must match. Regular expressions are
+    /// evaluated by Echarts only, [`DataTransform::apply`] rejects them.
+    pub fn reg<S: Into<String>>(mut self, reg: S) -> Self {
+        self.reg = Some(reg.into());
+        self
+    }
+
+    pub fn parser<P: Into<TransformParser>>(mut self, parser: P) -> Self {
+        self.parser = Some(parser.into());
+        self
+    }
+}
+
+/// The condition of a `filter` transform.
+///
+/// ```rust
+/// use charming::datatype::{Comparison, FilterCondition};
+///
+/// let condition = FilterCondition::and(vec![
+///     FilterCondition::from(Comparison::new("Year").gte(1950)),
+///     FilterCondition::not(Comparison::new("Country").eq("Germany")),
+/// ]);
+/// ```
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum FilterCondition {
+    And { and: Vec<FilterCondition> },
+    Or { or: Vec<FilterCondition> },
+    Not { not: Box<FilterCondition> },
+    Comparison(Box<Comparison>),
+}
+
+impl FilterCondition {
+    pub fn and<C: Into<FilterCondition>>(conditions: Vec<C>) -> Self {
+        FilterCondition::And {
+            and: conditions.into_iter().map(|c| c.into()).collect(),
+        }
+    }
+
+    pub fn or<C: Into<FilterCondition>>(conditions: Vec<C>) -> Self {
+        FilterCondition::Or {
+            or: conditions.into_iter().map(|c| c.into()).collect(),
+        }
+    }
+
+    pub fn not<C: Into<FilterCondition>>(condition: C) -> Self {
+        FilterCondition::Not {
+            not: Box::new(condition.into()),
+        }
+    }
+}
+
+impl From<Comparison> for FilterCondition {
+    fn from(comparison: Comparison) -> Self {
+        FilterCondition::Comparison(Box::new(comparison))
+    }
+}
+
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+/// Where values that cannot be compared are placed by a `sort` transform.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Incomparable {
+    Min,
+    Max,
+}
+
+/// A sort key of a `sort` transform.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SortKey {
+    dimension: TransformDimension,
+
+    order: Option<SortOrder>,
+
+    parser: Option<TransformParser>,
+
+    incomparable: Option<Incomparable>,
+}
+
+impl SortKey {
+    pub fn new<D: Into<TransformDimension>>(dimension: D) -> Self {
+        Self {
+            dimension: dimension.into(),
+            order: None,
+            parser: None,
+            incomparable: None,
+        }
+    }
+
+    pub fn order<S: Into<SortOrder>>(mut self, order: S) -> Self {
+        self.order = Some(order.into());
+        self
+    }
+
+    pub fn parser<P: Into<TransformParser>>(mut self, parser: P) -> Self {
+        self.parser = Some(parser.into());
+        self
+    }
+
+    pub fn incomparable<I: Into<Incomparable>>(mut self, incomparable: I) -> Self {
+        self.incomparable = Some(incomparable.into());
+        self
+    }
+}
+
+/// Config of the `boxplot` transform, which turns every row of samples into
+/// a row of `[name, low, Q1, Q2, Q3, high]`.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BoxplotConfig {
+    item_name_formatter: Option<String>,
+
+    #[serde(rename = "boundIQR")]
+    bound_iqr: Option<f64>,
+}
+
+impl BoxplotConfig {
+    pub fn new() -> Self {
+        Self {
+            item_name_formatter: None,
+            bound_iqr: None,
+        }
+    }
+
+    /// Template of the item names, where `{value}` is replaced by the index
+    /// of the row.
+    pub fn item_name_formatter<S: Into<String>>(mut self, item_name_formatter: S) -> Self {
+        self.item_name_formatter = Some(item_name_formatter.into());
+        self
+    }
+
+    /// Multiple of the interquartile range beyond which samples are outliers,
+    /// defaults to 1.5.
+    pub fn bound_iqr<F: Into<f64>>(mut self, bound_iqr: F) -> Self {
+        self.bound_iqr = Some(bound_iqr.into());
+        self
+    }
+}
+
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum AggregateMethod {
+    #[serde(rename = "sum")]
+    Sum,
+    #[serde(rename = "count")]
+    Count,
+    #[serde(rename = "first")]
+    First,
+    #[serde(rename = "average")]
+    Average,
+    #[serde(rename = "min")]
+    Min,
+    #[serde(rename = "max")]
+    Max,
+    #[serde(rename = "Q1")]
+    Q1,
+    #[serde(rename = "median")]
+    Median,
+    #[serde(rename = "Q3")]
+    Q3,
+}
+
+/// A result dimension of an `aggregate` transform.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AggregateDimension {
+    from: TransformDimension,
+
+    method: Option<AggregateMethod>,
+
+    name: Option<String>,
+}
+
+impl AggregateDimension {
+    pub fn new<D: Into<TransformDimension>>(from: D) -> Self {
+        Self {
+            from: from.into(),
+            method: None,
+            name: None,
+        }
+    }
+
+    pub fn method<M: Into<AggregateMethod>>(mut self, method: M) -> Self {
+        self.method = Some(method.into());
+        self
+    }
+
+    pub fn name<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+}
+
+/// Config of the `ecSimpleTransform:aggregate` transform. The transform is
+/// provided by the `echarts-simple-transform` package, which must be
+/// registered with `echarts.registerTransform` on the page.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AggregateConfig {
+    result_dimensions: Vec<AggregateDimension>,
+
+    group_by: Option<TransformDimension>,
+}
+
+impl AggregateConfig {
+    pub fn new() -> Self {
+        Self {
+            result_dimensions: vec![],
+            group_by: None,
+        }
+    }
+
+    pub fn result_dimension<A: Into<AggregateDimension>>(mut self, dimension: A) -> Self {
+        self.result_dimensions.push(dimension.into());
+        self
+    }
+
+    pub fn group_by<D: Into<TransformDimension>>(mut self, group_by: D) -> Self {
+        self.group_by = Some(group_by.into());
+        self
+    }
+}
+
+/// The transform applied by a [`super::Transform`] of a dataset.
+///
+/// Typed transforms can be evaluated in Rust with [`DataTransform::apply`],
+/// which mirrors what Echarts does in the browser and makes them easy to
+/// test. [`DataTransform::Raw`] is passed to Echarts verbatim.
+///
+/// ```rust
+/// use charming::datatype::{Comparison, DataTransform, Dataset, FilterCondition, Transform};
+///
+/// let dataset = Dataset::new()
+///     .source(charming::ds!(["Year", "Country"], [1950, "Germany"], [1940, "France"]))
+///     .transform(
+///         Transform::new()
+///             .from_dataset_index(0)
+///             .transform(FilterCondition::and(vec![
+///                 Comparison::new("Year").gte(1950),
+///                 Comparison::new("Country").eq("Germany"),
+///             ])),
+///     );
+/// ```
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "config")]
+pub enum DataTransform {
+    #[serde(rename = "filter")]
+    Filter(FilterCondition),
+    #[serde(rename = "sort")]
+    Sort(Vec<SortKey>),
+    #[serde(rename = "boxplot")]
+    Boxplot(BoxplotConfig),
+    #[serde(rename = "ecSimpleTransform:aggregate")]
+    Aggregate(AggregateConfig),
+    #[serde(untagged)]
+    Raw(RawString),
+}
+
+impl From<FilterCondition> for DataTransform {
+    fn from(condition: FilterCondition) -> Self {
+        DataTransform::Filter(condition)
+    }
+}
+
+impl From<Comparison> for DataTransform {
+    fn from(comparison: Comparison) -> Self {
+        DataTransform::Filter(comparison.into())
+    }
+}
+
+impl From<SortKey> for DataTransform {
+    fn from(key: SortKey) -> Self {
+        DataTransform::Sort(vec![key])
+    }
+}
+
+impl From<Vec<SortKey>> for DataTransform {
+    fn from(keys: Vec<SortKey>) -> Self {
+        DataTransform::Sort(keys)
+    }
+}
+
+impl From<BoxplotConfig> for DataTransform {
+    fn from(config: BoxplotConfig) -> Self {
+        DataTransform::Boxplot(config)
+    }
+}
+
+impl From<AggregateConfig> for DataTransform {
+    fn from(config: AggregateConfig) -> Self {
+        DataTransform::Aggregate(config)
+    }
+}
+
+impl From<RawString> for DataTransform {
+    fn from(raw: RawString) -> Self {
+        DataTransform::Raw(raw)
+    }
+}
+
+impl From<&str> for DataTransform {
+    fn from(raw: &str) -> Self {
+        DataTransform::Raw(raw.into())
+    }
+}
+
+impl From<String> for DataTransform {
+    fn from(raw: String) -> Self {
+        DataTransform::Raw(raw.into())
+    }
+}
+
+/// A table of rows with named dimensions, the input and output of
+/// [`DataTransform::apply`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataTable {
+    pub dimensions: Vec<String>,
+    pub rows: Vec<Vec<CompositeValue>>,
+}
+
+impl DataTable {
+    pub fn new<S: Into<String>>(dimensions: Vec<S>, rows: Vec<Vec<CompositeValue>>) -> Self {
+        Self {
+            dimensions: dimensions.into_iter().map(|d| d.into()).collect(),
+            rows,
+        }
+    }
+
+    fn index(&self, dimension: &TransformDimension) -> Result<usize, EchartsError> {
+        match dimension {
+            TransformDimension::Index(i) if *i >= 0 => Ok(*i as usize),
+            TransformDimension::Name(name) => self
+                .dimensions
+                .iter()
+                .position(|d| d == name)
+                .ok_or_else(|| EchartsError::DatasetError(format!("unknown dimension {}", name))),
+            TransformDimension::Index(i) => Err(EchartsError::DatasetError(format!(
+                "invalid dimension index {}",
+                i
+            ))),
+        }
+    }
+}
+
+impl DataTransform {
+    /// Applies the transform to a table the same way Echarts would.
+    ///
+    /// The `boxplot` transform only yields its first result, without the
+    /// outliers. Raw transforms and regular expressions in filters cannot be
+    /// evaluated and return an error.
+    pub fn apply(&self, table: &DataTable) -> Result<DataTable, EchartsError> {
+        match self {
+            DataTransform::Filter(condition) => {
+                let mut rows = vec![];
+                for row in &table.rows {
+                    if matches(condition, table, row)? {
+                        rows.push(row.clone());
+                    }
+                }
+                Ok(DataTable::new(table.dimensions.clone(), rows))
+            }
+            DataTransform::Sort(keys) => {
+                let keys = keys
+                    .iter()
+                    .map(|key| Ok((table.index(&key.dimension)?, key)))
+                    .collect::<Result<Vec<_>, EchartsError>>()?;
+                let mut rows = table.rows.clone();
+                rows.sort_by(|a, b| {
+                    keys.iter()
+                        .map(|(index, key)| compare_sort_key(key, a.get(*index), b.get(*index)))
+                        .find(|ordering| *ordering != Ordering::Equal)
+                        .unwrap_or(Ordering::Equal)
+                });
+                Ok(DataTable::new(table.dimensions.clone(), rows))
+            }
+            DataTransform::Boxplot(config) => Ok(boxplot(config, table)),
+            DataTransform::Aggregate(config) => aggregate(config, table),
+            DataTransform::Raw(_) => Err(EchartsError::DatasetError(
+                "raw transforms cannot be evaluated".to_string(),
+            )),
+        }
+    }
+}
+
+fn matches(
+    condition: &FilterCondition,
+    table: &DataTable,
+    row: &[CompositeValue],
+) -> Result<bool, EchartsError> {
+    match condition {
+        FilterCondition::And { and } => {
+            for c in and {
+                if !matches(c, table, row)? {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        }
+        FilterCondition::Or { or } => {
+            for c in or {
+                if matches(c, table, row)? {
+                    return Ok(true);
+                }
+            }
+            Ok(false)
+        }
+        FilterCondition::Not { not } => Ok(!matches(not, table, row)?),
+        FilterCondition::Comparison(c) => {
+            if c.reg.is_some() {
+                return Err(EchartsError::DatasetError(
+                    "regular expressions cannot be evaluated".to_string(),
+                ));
+            }
+            let value = parse(
+                row.get(table.index(&c.dimension)?)
+                    .unwrap_or(&CompositeValue::Null),
+                c.parser.as_ref(),
+            );
+            let parse_rhs = |v: &CompositeValue| parse(v, c.parser.as_ref());
+            let relation = |rhs: &CompositeValue, f: fn(f64, f64) -> bool| match (
+                as_number(&value),
+                as_number(&parse_rhs(rhs)),
+            ) {
+                (Some(a), Some(b)) => f(a, b),
+                _ => false,
+            };
+
+            Ok(c.eq
+                .as_ref()
+                .is_none_or(|rhs| equals(&value, &parse_rhs(rhs)))
+                && c.ne
+                    .as_ref()
+                    .is_none_or(|rhs| !equals(&value, &parse_rhs(rhs)))
+                && c.lt.as_ref().is_none_or(|rhs| relation(rhs, |a, b| a < b))
+                && c.lte
+                    .as_ref()
+                    .is_none_or(|rhs| relation(rhs, |a, b| a <= b))
+                && c.gt.as_ref().is_none_or(|rhs| relation(rhs, |a, b| a > b))
+                && c.gte
+                    .as_ref()
+                    .is_none_or(|rhs| relation(rhs, |a, b| a >= b)))
+        }
+    }
+}
+
+fn compare_sort_key(
+    key: &SortKey,
+    a: Option<&CompositeValue>,
+    b: Option<&CompositeValue>,
+) -> Ordering {
+    let a = parse(a.unwrap_or(&CompositeValue::Null), key.parser.as_ref());
+    let b = parse(b.unwrap_or(&CompositeValue::Null), key.parser.as_ref());
+
+    let incomparable = match key.incomparable {
+        Some(Incomparable::Max) => Ordering::Greater,
+        _ => Ordering::Less,
+    };
+    let ordering = match (as_number(&a), as_number(&b), &a, &b) {
+        (Some(x), Some(y), _, _) => x.partial_cmp(&y).unwrap_or(Ordering::Equal),
+        (None, None, CompositeValue::String(x), CompositeValue::String(y)) => x.cmp(y),
+        (None, None, _, _) => Ordering::Equal,
+        (Some(_), None, _, _) => incomparable.reverse(),
+        (None, Some(_), _, _) => incomparable,
+    };
+
+    match key.order {
+        Some(SortOrder::Desc) => ordering.reverse(),
+        _ => ordering,
+    }
+}
+
+fn parse(value: &CompositeValue, parser: Option<&TransformParser>) -> CompositeValue {
+    match (parser, value) {
+        (Some(TransformParser::Trim), CompositeValue::String(s)) => {
+            CompositeValue::String(s.trim().to_string())
+        }
+        (Some(TransformParser::Number), CompositeValue::String(s)) => s
+            .trim()
+            .parse::<f64>()
+            .map(CompositeValue::from)
+            .unwrap_or(CompositeValue::Null),
+        (Some(TransformParser::Time), CompositeValue::String(s)) => parse_time(s)
+            .map(CompositeValue::from)
+            .unwrap_or(CompositeValue::Null),
+        _ => value.clone(),
+    }
+}
+
+/// Parses `YYYY-MM-DD[ T]HH:MM[:SS[.sss]][Z]` into milliseconds since the Unix
+/// epoch, interpreting the time as UTC.
+fn parse_time(s: &str) -> Option<f64> {
+    let s = s.trim().trim_end_matches('Z');
+    let (date, time) = match s.find(['T', ' ']) {
+        Some(i) => (&s[..i], &s[i + 1..]),
+        None => (s, ""),
+    };
+
+    let mut date = date.split('-').map(|p| p.parse::<i64>().ok());
+    let (y, m, d) = (
+        date.next()??,
+        date.next().flatten().unwrap_or(1),
+        date.next().flatten().unwrap_or(1),
+    );
+
+    let mut time = time.split(':').filter(|p| !p.is_empty());
+    let hours = time.next().map_or(Some(0.0), |p| p.parse::<f64>().ok())?;
+    let minutes = time.next().map_or(Some(0.0), |p| p.parse::<f64>().ok())?;
+    let seconds = time.next().map_or(Some(0.0), |p| p.parse::<f64>().ok())?;
+
+    // Days since the Unix epoch in the proleptic Gregorian calendar.
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = (if y >= 0 { y } else { y - 399 }) / 400;
+    let yoe = y - era * 400;
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146097 + doe - 719468;
+
+    Some(((days as f64 * 24.0 + hours) * 60.0 + minutes) * 60_000.0 + seconds * 1000.0)
+}
+
+fn as_number(value: &CompositeValue) -> Option<f64> {
+    match value {
+        CompositeValue::Number(NumericValue::Integer(i)) => Some(*i as f64),
+        CompositeValue::Number(NumericValue::Float(f)) if !f.is_nan() => Some(*f),
+        CompositeValue::String(s) => s.trim().parse::<f64>().ok(),
+        _ => None,
+    }
+}
+
+fn equals(a: &CompositeValue, b: &CompositeValue) -> bool {
+    match (a, b) {
+        (CompositeValue::String(x), CompositeValue::String(y)) => x == y,
+        _ => match (as_number(a), as_number(b)) {
+            (Some(x), Some(y)) => x == y,
+            _ => a == b,
+        },
+    }
+}
+
+/// Quantile of sorted samples, using the same interpolation as Echarts.
+fn quantile(sorted: &[f64], p: f64) -> f64 {
+    let h = (sorted.len() - 1) as f64 * p + 1.0;
+    let i = h.floor() as usize;
+    let v = sorted[i - 1];
+    let e = h - i as f64;
+    if e > 0.0 {
+        v + e * (sorted[i] - v)
+    } else {
+        v
+    }
+}
+
+fn boxplot(config: &BoxplotConfig, table: &DataTable) -> DataTable {
+    let bound = config.bound_iqr.unwrap_or(1.5);
+    let rows = table
+        .rows
+        .iter()
+        .enumerate()
+        .map(|(index, row)| {
+            let mut samples: Vec<f64> = row.iter().filter_map(as_number).collect();
+            samples.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+            let name = match &config.item_name_formatter {
+                Some(formatter) => formatter.replace("{value}", &index.to_string()),
+                None => index.to_string(),
+            };
+            let mut result = vec![CompositeValue::from(name)];
+            if samples.is_empty() {
+                result.extend(std::iter::repeat_n(CompositeValue::Null, 5));
+                return result;
+            }
+
+            let q1 = quantile(&samples, 0.25);
+            let q2 = quantile(&samples, 0.5);
+            let q3 = quantile(&samples, 0.75);
+            let iqr = q3 - q1;
+            let low = (q1 - bound * iqr).max(samples[0]);
+            let high = (q3 + bound * iqr).min(samples[samples.len() - 1]);
+            result.extend(
+                [low, q1, q2, q3, high]
+                    .into_iter()
+                    .map(CompositeValue::from),
+            );
+            result
+        })
+        .collect();
+
+    DataTable::new(vec!["ItemName", "Low", "Q1", "Q2", "Q3", "High"], rows)
+}
+
+fn aggregate(config: &AggregateConfig, table: &DataTable) -> Result<DataTable, EchartsError> {
+    let columns = config
+        .result_dimensions
+        .iter()
+        .map(|d| Ok((table.index(&d.from)?, d)))
+        .collect::<Result<Vec<_>, EchartsError>>()?;
+
+    let mut groups: Vec<(Option<&CompositeValue>, Vec<&Vec<CompositeValue>>)> = vec![];
+    match &config.group_by {
+        Some(group_by) => {
+            let index = table.index(group_by)?;
+            for row in &table.rows {
+                let key = row.get(index);
+                match groups.iter_mut().find(|(k, _)| *k == key) {
+                    Some((_, rows)) => rows.push(row),
+                    None => groups.push((key, vec![row])),
+                }
+            }
+        }
+        None => groups.push((None, table.rows.iter().collect())),
+    }
+
+    let dimensions = columns
+        .iter()
+        .map(|(index, d)| match &d.name {
+            Some(name) => name.clone(),
+            None => table.dimensions.get(*index).cloned().unwrap_or_default(),
+        })
+        .collect::<Vec<_>>();
+
+    let rows = groups
+        .into_iter()
+        .map(|(_, rows)| {
+            columns
+                .iter()
+                .map(|(index, d)| {
+                    let values = rows.iter().filter_map(|row| row.get(*index));
+                    let mut numbers: Vec<f64> = values.clone().filter_map(as_number).collect();
+                    numbers.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+                    let number =
+                        |n: Option<f64>| n.map_or(CompositeValue::Null, CompositeValue::from);
+                    let quartile = |p: f64| {
+                        number(
+                            Some(&numbers)
+                                .filter(|n| !n.is_empty())
+                                .map(|n| quantile(n, p)),
+                        )
+                    };
+
+                    match d.method.as_ref().unwrap_or(&AggregateMethod::First) {
+                        AggregateMethod::First => values
+                            .clone()
+                            .next()
+                            .cloned()
+                            .unwrap_or(CompositeValue::Null),
+                        AggregateMethod::Count => CompositeValue::from(rows.len() as i32),
+                        AggregateMethod::Sum => CompositeValue::from(numbers.iter().sum::<f64>()),
+                        AggregateMethod::Average => number(
+                            Some(numbers.iter().sum::<f64>() / numbers.len() as f64)
+                                .filter(|_| !numbers.is_empty()),
+                        ),
+                        AggregateMethod::Min => number(numbers.first().copied()),
+                        AggregateMethod::Max => number(numbers.last().copied()),
+                        AggregateMethod::Q1 => quartile(0.25),
+                        AggregateMethod::Median => quartile(0.5),
+                        AggregateMethod::Q3 => quartile(0.75),
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    Ok(DataTable::new(dimensions, rows))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn table() -> DataTable {
+        DataTable::new(
+            vec!["Country", "Year", "Income"],
+            vec![
+                vec!["Germany".into(), 1940.into(), 5000.into()],
+                vec!["France".into(), 1950.into(), 6000.into()],
+                vec!["Germany".into(), 1960.into(), 9000.into()],
+                vec!["France".into(), 1970.into(), 11000.into()],
+            ],
+        )
+    }
+
+    #[test]
+    fn filter_serialization() {
+        let transform: DataTransform = FilterCondition::and(vec![
+            FilterCondition::from(Comparison::new("Year").gte(1950)),
+            FilterCondition::not(Comparison::new("Country").eq("Germany")),
+        ])
+        .into();
+        assert_eq!(
+            serde_json::to_value(&transform).unwrap(),
+            serde_json::json!({
+                "type": "filter",
+                "config": {
+                    "and": [
+                        {"dimension": "Year", ">=": 1950},
+                        {"not": {"dimension": "Country", "=": "Germany"}}
+                    ]
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn filter_evaluation() {
+        let transform = DataTransform::from(FilterCondition::or(vec![
+            FilterCondition::and(vec![
+                Comparison::new("Country").eq("Germany"),
+                Comparison::new("Year").gt(1950),
+            ]),
+            Comparison::new(1).lt(1960).ne("1940").into(),
+        ]));
+        let result = transform.apply(&table()).unwrap();
+        assert_eq!(
+            result.rows,
+            vec![table().rows[1].clone(), table().rows[2].clone()]
+        );
+
+        let raw = DataTransform::from(r#"{"type": "filter"}"#);
+        assert!(raw.apply(&table()).is_err());
+    }
+
+    #[test]
+    fn sort_evaluation() {
+        let transform = DataTransform::from(vec![
+            SortKey::new("Country"),
+            SortKey::new("Income").order(SortOrder::Desc),
+        ]);
+        let result = transform.apply(&table()).unwrap();
+        let years: Vec<_> = result.rows.iter().map(|row| row[1].clone()).collect();
+        assert_eq!(
+            years,
+            vec![1970.into(), 1950.into(), 1960.into(), 1940.into()]
+        );
+    }
+
+    #[test]
+    fn time_parser() {
+        assert_eq!(parse_time("1970-01-02"), Some(86_400_000.0));
+        assert_eq!(parse_time("2000-03-01T00:00:01Z"), Some(951_868_801_000.0));
+        assert_eq!(parse_time("Monday"), None);
+    }
+
+    #[test]
+    fn boxplot_evaluation() {
+        let table = DataTable::new(
+            vec!["a", "b", "c", "d", "e"],
+            vec![vec![1.into(), 2.into(), 3.into(), 4.into(), 100.into()]],
+        );
+        let result = DataTransform::from(BoxplotConfig::new().item_name_formatter("expr {value}"))
+            .apply(&table)
+            .unwrap();
+        assert_eq!(
+            result.rows,
+            vec![vec![
+                "expr 0".into(),
+                1.0.into(),
+                2.0.into(),
+                3.0.into(),
+                4.0.into(),
+                7.0.into()
+            ]]
+        );
+    }
+
+    #[test]
+    fn aggregate_evaluation() {
+        let transform = DataTransform::from(
+            AggregateConfig::new()
+                .result_dimension(AggregateDimension::new("Country"))
+                .result_dimension(
+                    AggregateDimension::new("Income")
+                        .method(AggregateMethod::Sum)
+                        .name("Total"),
+                )
+                .group_by("Country"),
+        );
+        assert_eq!(
+            serde_json::to_value(&transform).unwrap()["config"],
+            serde_json::json!({
+                "resultDimensions": [
+                    {"from": "Country"},
+                    {"from": "Income", "method": "sum", "name": "Total"}
+                ],
+                "groupBy": "Country"
+            })
+        );
+
+        let result = transform.apply(&table()).unwrap();
+        assert_eq!(result.dimensions, vec!["Country", "Total"]);
+        assert_eq!(
+            result.rows,
+            vec![
+                vec!["Germany".into(), 14000.0.into()],
+                vec!["France".into(), 17000.0.into()],
+            ]
+        );
+    }
+}
@@ -19,12 +19,50 @@ impl From<f64> for NumericValue {
     }
 }
 
+impl From<f32> for NumericValue {
+    fn from(n: f32) -> Self {
+        NumericValue::Float(n as f64)
+    }
+}
+
+macro_rules! impl_from_small_integer {
+    ($($type:ty),*) => {
+        $(
+            impl From<$type> for NumericValue {
+                fn from(n: $type) -> Self {
+                    NumericValue::Integer(n as i32)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_small_integer!(i8, i16, u8, u16);
+
+/// Integers that do not fit in an `i32` become floats.
+macro_rules! impl_from_large_integer {
+    ($($type:ty),*) => {
+        $(
+            impl From<$type> for NumericValue {
+                fn from(n: $type) -> Self {
+                    i32::try_from(n).map_or(NumericValue::Float(n as f64), NumericValue::Integer)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_large_integer!(i64, u32, u64, isize, usize);
+
+/// A single value in a data item. [`CompositeValue::Null`] represents a missing
+/// value and is serialized as `null`, which Echarts treats the same as `'-'`.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum CompositeValue {
     Number(NumericValue),
     String(String),
     Array(Vec<CompositeValue>),
+    Null,
 }
 
 impl<N> From<N> for CompositeValue
@@ -48,6 +86,18 @@ impl From<String> for CompositeValue {
     }
 }
 
+impl<V> From<Option<V>> for CompositeValue
+where
+    V: Into<CompositeValue>,
+{
+    fn from(v: Option<V>) -> Self {
+        match v {
+            Some(v) => v.into(),
+            None => CompositeValue::Null,
+        }
+    }
+}
+
 impl<V> From<Vec<V>> for CompositeValue
 where
     V: Into<CompositeValue>,
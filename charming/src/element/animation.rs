@@ -0,0 +1,270 @@
+use serde::{Deserialize, Serialize};
+use macros::serde_auto;
+
+use super::RawString;
+
+/// Easing function of an animation.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AnimationEasing {
+    Linear,
+    QuadraticIn,
+    QuadraticOut,
+    QuadraticInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    QuarticIn,
+    QuarticOut,
+    QuarticInOut,
+    QuinticIn,
+    QuinticOut,
+    QuinticInOut,
+    SinusoidalIn,
+    SinusoidalOut,
+    SinusoidalInOut,
+    ExponentialIn,
+    ExponentialOut,
+    ExponentialInOut,
+    CircularIn,
+    CircularOut,
+    CircularInOut,
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+    BackIn,
+    BackOut,
+    BackInOut,
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+}
+
+/// Duration or delay of an animation in milliseconds, either fixed or
+/// computed per data item by a JavaScript callback such as
+/// `function (idx) { return idx * 10; }`.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum AnimationTime {
+    Number(f64),
+    Function(RawString),
+}
+
+impl From<f64> for AnimationTime {
+    fn from(time: f64) -> Self {
+        AnimationTime::Number(time)
+    }
+}
+
+impl From<i32> for AnimationTime {
+    fn from(time: i32) -> Self {
+        AnimationTime::Number(time as f64)
+    }
+}
+
+impl From<RawString> for AnimationTime {
+    fn from(time: RawString) -> Self {
+        AnimationTime::Function(time)
+    }
+}
+
+/// Animation of state transitions such as hover highlighting.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StateAnimation {
+    duration: Option<f64>,
+
+    easing: Option<AnimationEasing>,
+}
+
+impl StateAnimation {
+    pub fn new() -> Self {
+        Self {
+            duration: None,
+            easing: None,
+        }
+    }
+
+    pub fn duration<F: Into<f64>>(mut self, duration: F) -> Self {
+        self.duration = Some(duration.into());
+        self
+    }
+
+    pub fn easing<E: Into<AnimationEasing>>(mut self, easing: E) -> Self {
+        self.easing = Some(easing.into());
+        self
+    }
+}
+
+/// Animation options of the chart, a series or a mark, flattened into its
+/// options.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Animation {
+    pub(crate) animation: Option<bool>,
+
+    pub(crate) animation_threshold: Option<f64>,
+
+    pub(crate) animation_duration: Option<AnimationTime>,
+
+    pub(crate) animation_easing: Option<AnimationEasing>,
+
+    pub(crate) animation_delay: Option<AnimationTime>,
+
+    pub(crate) animation_duration_update: Option<AnimationTime>,
+
+    pub(crate) animation_easing_update: Option<AnimationEasing>,
+
+    pub(crate) animation_delay_update: Option<AnimationTime>,
+}
+
+impl Animation {
+    pub(crate) fn new() -> Self {
+        Self {
+            animation: None,
+            animation_threshold: None,
+            animation_duration: None,
+            animation_easing: None,
+            animation_delay: None,
+            animation_duration_update: None,
+            animation_easing_update: None,
+            animation_delay_update: None,
+        }
+    }
+}
+
+/// Implements the animation builders for types with an `animation:
+/// Animation` field.
+macro_rules! impl_animation {
+    ($type:ty) => {
+        impl $type {
+            pub fn animation(mut self, animation: bool) -> Self {
+                self.animation.animation = Some(animation);
+                self
+            }
+
+            /// Number of graphic elements above which animation is disabled.
+            pub fn animation_threshold<F: Into<f64>>(mut self, animation_threshold: F) -> Self {
+                self.animation.animation_threshold = Some(animation_threshold.into());
+                self
+            }
+
+            pub fn animation_duration<A: Into<$crate::element::AnimationTime>>(
+                mut self,
+                animation_duration: A,
+            ) -> Self {
+                self.animation.animation_duration = Some(animation_duration.into());
+                self
+            }
+
+            pub fn animation_easing<E: Into<$crate::element::AnimationEasing>>(
+                mut self,
+                animation_easing: E,
+            ) -> Self {
+                self.animation.animation_easing = Some(animation_easing.into());
+                self
+            }
+
+            pub fn animation_delay<A: Into<$crate::element::AnimationTime>>(
+                mut self,
+                animation_delay: A,
+            ) -> Self {
+                self.animation.animation_delay = Some(animation_delay.into());
+                self
+            }
+
+            pub fn animation_duration_update<A: Into<$crate::element::AnimationTime>>(
+                mut self,
+                animation_duration_update: A,
+            ) -> Self {
+                self.animation.animation_duration_update = Some(animation_duration_update.into());
+                self
+            }
+
+            pub fn animation_easing_update<E: Into<$crate::element::AnimationEasing>>(
+                mut self,
+                animation_easing_update: E,
+            ) -> Self {
+                self.animation.animation_easing_update = Some(animation_easing_update.into());
+                self
+            }
+
+            pub fn animation_delay_update<A: Into<$crate::element::AnimationTime>>(
+                mut self,
+                animation_delay_update: A,
+            ) -> Self {
+                self.animation.animation_delay_update = Some(animation_delay_update.into());
+                self
+            }
+        }
+    };
+}
+
+pub(crate) use impl_animation;
+
+/// How the graphics of a chart are composited onto each other.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BlendMode {
+    SourceOver,
+    Lighter,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        element::MarkPoint,
+        series::{Bar, Series},
+        Chart,
+    };
+
+    use super::*;
+
+    #[test]
+    fn chart_animation() {
+        let chart = Chart::new()
+            .dark_mode(true)
+            .use_utc(true)
+            .animation_easing(AnimationEasing::ElasticOut)
+            .blend_mode(BlendMode::SourceOver)
+            .series(
+                Bar::new()
+                    .animation_delay(RawString::from("function (idx) { return idx * 10; }"))
+                    .data(vec![1, 2]),
+            );
+        let value = serde_json::to_value(&chart).unwrap();
+        assert_eq!(value["darkMode"], true);
+        assert_eq!(value["useUTC"], true);
+        assert_eq!(value["animationEasing"], "elasticOut");
+        assert_eq!(value["blendMode"], "source-over");
+        assert!(chart
+            .to_string()
+            .contains(r#""animationDelay": function (idx) { return idx * 10; }"#));
+    }
+
+    #[test]
+    fn series_animation_flattened() {
+        let series = Series::from(
+            Bar::new()
+                .animation(false)
+                .animation_duration(1000)
+                .mark_point(MarkPoint::new().animation_easing(AnimationEasing::BounceOut)),
+        );
+        let value = serde_json::to_value(&series).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "type": "bar",
+                "animation": false,
+                "animationDuration": 1000.0,
+                "markPoint": { "animationEasing": "bounceOut" },
+            })
+        );
+        assert_eq!(serde_json::from_value::<Series>(value).unwrap(), series);
+    }
+}
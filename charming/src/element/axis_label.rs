@@ -1,7 +1,17 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 use macros::serde_auto;
 
-use super::{color::Color, Formatter};
+use crate::datatype::CompositeValue;
+
+use super::{
+    color::Color,
+    label::{LabelAlign, LabelVerticalAlign},
+    padding::Padding,
+    text_style::{TextOverflow, TextStyle},
+    Formatter,
+};
 
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -20,6 +30,38 @@ pub struct AxisLabel {
     rotate: Option<f64>,
 
     interval: Option<f64>,
+
+    font_style: Option<String>,
+
+    font_weight: Option<String>,
+
+    font_family: Option<String>,
+
+    line_height: Option<f64>,
+
+    align: Option<LabelAlign>,
+
+    vertical_align: Option<LabelVerticalAlign>,
+
+    background_color: Option<Color>,
+
+    border_color: Option<Color>,
+
+    border_width: Option<f64>,
+
+    border_radius: Option<f64>,
+
+    padding: Option<Padding>,
+
+    width: Option<CompositeValue>,
+
+    height: Option<f64>,
+
+    overflow: Option<TextOverflow>,
+
+    ellipsis: Option<String>,
+
+    rich: Option<BTreeMap<String, TextStyle>>,
 }
 
 impl AxisLabel {
@@ -32,6 +74,22 @@ impl AxisLabel {
             formatter: None,
             rotate: None,
             interval: None,
+            font_style: None,
+            font_weight: None,
+            font_family: None,
+            line_height: None,
+            align: None,
+            vertical_align: None,
+            background_color: None,
+            border_color: None,
+            border_width: None,
+            border_radius: None,
+            padding: None,
+            width: None,
+            height: None,
+            overflow: None,
+            ellipsis: None,
+            rich: None,
         }
     }
 
@@ -69,4 +127,89 @@ impl AxisLabel {
         self.interval = Some(interval.into());
         self
     }
+
+    pub fn font_style<S: Into<String>>(mut self, font_style: S) -> Self {
+        self.font_style = Some(font_style.into());
+        self
+    }
+
+    pub fn font_weight<S: Into<String>>(mut self, font_weight: S) -> Self {
+        self.font_weight = Some(font_weight.into());
+        self
+    }
+
+    pub fn font_family<S: Into<String>>(mut self, font_family: S) -> Self {
+        self.font_family = Some(font_family.into());
+        self
+    }
+
+    pub fn line_height<F: Into<f64>>(mut self, line_height: F) -> Self {
+        self.line_height = Some(line_height.into());
+        self
+    }
+
+    pub fn align<A: Into<LabelAlign>>(mut self, align: A) -> Self {
+        self.align = Some(align.into());
+        self
+    }
+
+    pub fn vertical_align<V: Into<LabelVerticalAlign>>(mut self, vertical_align: V) -> Self {
+        self.vertical_align = Some(vertical_align.into());
+        self
+    }
+
+    pub fn background_color<C: Into<Color>>(mut self, background_color: C) -> Self {
+        self.background_color = Some(background_color.into());
+        self
+    }
+
+    pub fn border_color<C: Into<Color>>(mut self, border_color: C) -> Self {
+        self.border_color = Some(border_color.into());
+        self
+    }
+
+    pub fn border_width<F: Into<f64>>(mut self, border_width: F) -> Self {
+        self.border_width = Some(border_width.into());
+        self
+    }
+
+    pub fn border_radius<F: Into<f64>>(mut self, border_radius: F) -> Self {
+        self.border_radius = Some(border_radius.into());
+        self
+    }
+
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = Some(padding.into());
+        self
+    }
+
+    /// Width of the label box. Required for `overflow` to take effect.
+    pub fn width<C: Into<CompositeValue>>(mut self, width: C) -> Self {
+        self.width = Some(width.into());
+        self
+    }
+
+    pub fn height<F: Into<f64>>(mut self, height: F) -> Self {
+        self.height = Some(height.into());
+        self
+    }
+
+    pub fn overflow<O: Into<TextOverflow>>(mut self, overflow: O) -> Self {
+        self.overflow = Some(overflow.into());
+        self
+    }
+
+    pub fn ellipsis<S: Into<String>>(mut self, ellipsis: S) -> Self {
+        self.ellipsis = Some(ellipsis.into());
+        self
+    }
+
+    /// Add a named rich text style, referenced from the formatter as
+    /// `{name|text}`.
+    pub fn rich<S: Into<String>, T: Into<TextStyle>>(mut self, name: S, style: T) -> Self {
+        self.rich
+            .get_or_insert_with(BTreeMap::new)
+            .insert(name.into(), style.into());
+        self
+    }
 }
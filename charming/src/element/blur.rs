@@ -1,27 +1,48 @@
 use serde::{Deserialize, Serialize};
 use macros::serde_auto;
 
-use super::{item_style::ItemStyle, label::Label};
+use super::{
+    area_style::AreaStyle,
+    item_style::ItemStyle,
+    label::{Label, LabelLine},
+    line_style::LineStyle,
+};
 
+/// Style of the elements that are faded out while another element is
+/// focused.
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Blur {
-    label: Option<Label>,
+    label: Option<Box<Label>>,
+
+    label_line: Option<LabelLine>,
 
     item_style: Option<ItemStyle>,
+
+    line_style: Option<LineStyle>,
+
+    area_style: Option<AreaStyle>,
 }
 
 impl Blur {
     pub fn new() -> Self {
         Self {
             label: None,
+            label_line: None,
             item_style: None,
+            line_style: None,
+            area_style: None,
         }
     }
 
     pub fn label<L: Into<Label>>(mut self, label: L) -> Self {
-        self.label = Some(label.into());
+        self.label = Some(Box::new(label.into()));
+        self
+    }
+
+    pub fn label_line<L: Into<LabelLine>>(mut self, label_line: L) -> Self {
+        self.label_line = Some(label_line.into());
         self
     }
 
@@ -29,4 +50,14 @@ impl Blur {
         self.item_style = Some(item_style.into());
         self
     }
+
+    pub fn line_style<S: Into<LineStyle>>(mut self, line_style: S) -> Self {
+        self.line_style = Some(line_style.into());
+        self
+    }
+
+    pub fn area_style<S: Into<AreaStyle>>(mut self, area_style: S) -> Self {
+        self.area_style = Some(area_style.into());
+        self
+    }
 }
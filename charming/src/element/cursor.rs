@@ -6,4 +6,8 @@ use macros::serde_auto;
 #[serde(rename_all = "snake_case")]
 pub enum Cursor {
     Pointer,
+    Default,
+    Move,
+    Crosshair,
+    Help,
 }
@@ -15,6 +15,7 @@ pub struct DimensionEncode {
 
     item_name: Option<String>,
 
+    value: Option<CompositeValue>,
 
     tooltip: Vec<CompositeValue>,
 }
@@ -26,6 +27,7 @@ impl DimensionEncode {
             y: None,
             z: None,
             item_name: None,
+            value: None,
             tooltip: vec![],
         }
     }
@@ -50,6 +52,13 @@ impl DimensionEncode {
         self
     }
 
+    /// Dimension holding the value, for series without axes such as `Pie`
+    /// and `Funnel`.
+    pub fn value<C: Into<CompositeValue>>(mut self, value: C) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
     pub fn tooltip<S: Into<CompositeValue>>(mut self, tooltip: Vec<S>) -> Self {
         self.tooltip = tooltip.into_iter().map(|s| s.into()).collect();
         self
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use macros::serde_auto;
 
-use super::{item_style::ItemStyle, AreaStyle, Label};
+use super::{item_style::ItemStyle, AreaStyle, Label, LabelLine, LineStyle};
 
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -17,46 +17,154 @@ pub enum EmphasisFocus {
     Adjacency,
 }
 
+/// The range of other series that are blurred when an element is focused.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BlurScope {
+    /// Only series in the same coordinate system.
+    CoordinateSystem,
+
+    /// Only the series of the focused element.
+    Series,
+
+    /// All series in the chart.
+    Global,
+}
+
+/// Whether to scale up the hovered element, or the scale ratio to use.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum EmphasisScale {
+    Enabled(bool),
+    Ratio(f64),
+}
+
+impl From<bool> for EmphasisScale {
+    fn from(scale: bool) -> Self {
+        EmphasisScale::Enabled(scale)
+    }
+}
+
+impl From<f64> for EmphasisScale {
+    fn from(scale: f64) -> Self {
+        EmphasisScale::Ratio(scale)
+    }
+}
+
+impl From<i32> for EmphasisScale {
+    fn from(scale: i32) -> Self {
+        EmphasisScale::Ratio(scale as f64)
+    }
+}
+
+/// Style of an element while it is hovered.
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Emphasis {
+    disabled: Option<bool>,
+
     focus: Option<EmphasisFocus>,
 
+    blur_scope: Option<BlurScope>,
+
+    scale: Option<EmphasisScale>,
+
     item_style: Option<ItemStyle>,
 
+    line_style: Option<LineStyle>,
+
     area_style: Option<AreaStyle>,
 
-    label: Option<Label>,
+    label: Option<Box<Label>>,
+
+    label_line: Option<LabelLine>,
 }
 
 impl Emphasis {
     pub fn new() -> Self {
         Self {
+            disabled: None,
             focus: None,
+            blur_scope: None,
+            scale: None,
             item_style: None,
+            line_style: None,
             area_style: None,
             label: None,
+            label_line: None,
         }
     }
 
+    /// Whether to disable the emphasis state, which also saves the cost of
+    /// computing it.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = Some(disabled);
+        self
+    }
+
     pub fn focus<E: Into<EmphasisFocus>>(mut self, emphasis: E) -> Self {
         self.focus = Some(emphasis.into());
         self
     }
 
+    pub fn blur_scope<B: Into<BlurScope>>(mut self, blur_scope: B) -> Self {
+        self.blur_scope = Some(blur_scope.into());
+        self
+    }
+
+    pub fn scale<S: Into<EmphasisScale>>(mut self, scale: S) -> Self {
+        self.scale = Some(scale.into());
+        self
+    }
+
     pub fn item_style<I: Into<ItemStyle>>(mut self, item_style: I) -> Self {
         self.item_style = Some(item_style.into());
         self
     }
 
+    pub fn line_style<L: Into<LineStyle>>(mut self, line_style: L) -> Self {
+        self.line_style = Some(line_style.into());
+        self
+    }
+
     pub fn area_style<A: Into<AreaStyle>>(mut self, area_style: A) -> Self {
         self.area_style = Some(area_style.into());
         self
     }
 
     pub fn label<L: Into<Label>>(mut self, label: L) -> Self {
-        self.label = Some(label.into());
+        self.label = Some(Box::new(label.into()));
         self
     }
+
+    pub fn label_line<L: Into<LabelLine>>(mut self, label_line: L) -> Self {
+        self.label_line = Some(label_line.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn emphasis_focus_series() {
+        let emphasis = Emphasis::new()
+            .focus(EmphasisFocus::Series)
+            .blur_scope(BlurScope::CoordinateSystem)
+            .scale(1.2)
+            .line_style(LineStyle::new().width(4));
+        assert_eq!(
+            serde_json::to_value(&emphasis).unwrap(),
+            serde_json::json!({
+                "focus": "series",
+                "blurScope": "coordinateSystem",
+                "scale": 1.2,
+                "lineStyle": { "width": 4.0 },
+            })
+        );
+    }
 }
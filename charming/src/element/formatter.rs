@@ -17,6 +17,12 @@ impl From<&str> for Formatter {
     }
 }
 
+impl From<String> for Formatter {
+    fn from(s: String) -> Self {
+        Formatter::String(s)
+    }
+}
+
 impl From<RawString> for Formatter {
     fn from(s: RawString) -> Self {
         Formatter::Function(s)
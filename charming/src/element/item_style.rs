@@ -9,8 +9,14 @@ use super::{border_type::BorderType, color::Color};
 pub struct ItemStyle {
     color: Option<Color>,
 
+    color0: Option<Color>,
+
     border_color: Option<Color>,
 
+    border_color0: Option<Color>,
+
+    border_color_doji: Option<Color>,
+
     border_width: Option<f64>,
 
     border_radius: Option<f64>,
@@ -26,13 +32,20 @@ pub struct ItemStyle {
     shadow_offset_x: Option<f64>,
 
     shadow_offset_y: Option<f64>,
+
+    gap_width: Option<f64>,
+
+    border_color_saturation: Option<f64>,
 }
 
 impl ItemStyle {
     pub fn new() -> Self {
         Self {
             color: None,
+            color0: None,
             border_color: None,
+            border_color0: None,
+            border_color_doji: None,
             border_width: None,
             border_radius: None,
             border_type: None,
@@ -41,6 +54,8 @@ impl ItemStyle {
             shadow_blur: None,
             shadow_offset_x: None,
             shadow_offset_y: None,
+            gap_width: None,
+            border_color_saturation: None,
         }
     }
 
@@ -49,11 +64,30 @@ impl ItemStyle {
         self
     }
 
+    /// Fill color of falling candlesticks. [`ItemStyle::color`] is used
+    /// for rising ones.
+    pub fn color0<C: Into<Color>>(mut self, color0: C) -> Self {
+        self.color0 = Some(color0.into());
+        self
+    }
+
     pub fn border_color<C: Into<Color>>(mut self, border_color: C) -> Self {
         self.border_color = Some(border_color.into());
         self
     }
 
+    /// Border color of falling candlesticks.
+    pub fn border_color0<C: Into<Color>>(mut self, border_color0: C) -> Self {
+        self.border_color0 = Some(border_color0.into());
+        self
+    }
+
+    /// Border color of candlesticks whose open and close prices are equal.
+    pub fn border_color_doji<C: Into<Color>>(mut self, border_color_doji: C) -> Self {
+        self.border_color_doji = Some(border_color_doji.into());
+        self
+    }
+
     pub fn border_width<F: Into<f64>>(mut self, border_width: F) -> Self {
         self.border_width = Some(border_width.into());
         self
@@ -93,6 +127,19 @@ impl ItemStyle {
         self.shadow_offset_y = Some(shadow_offset_y.into());
         self
     }
+
+    /// Gap between the children of a treemap node.
+    pub fn gap_width<F: Into<f64>>(mut self, gap_width: F) -> Self {
+        self.gap_width = Some(gap_width.into());
+        self
+    }
+
+    /// Saturation of the border color of a treemap node, derived from the
+    /// node's own color.
+    pub fn border_color_saturation<F: Into<f64>>(mut self, border_color_saturation: F) -> Self {
+        self.border_color_saturation = Some(border_color_saturation.into());
+        self
+    }
 }
 
 impl From<Color> for ItemStyle {
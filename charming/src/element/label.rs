@@ -1,7 +1,17 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 use macros::serde_auto;
 
-use super::{color::Color, line_style::LineStyle, Formatter};
+use crate::datatype::CompositeValue;
+
+use super::{
+    border_type::BorderType,
+    color::Color,
+    line_style::LineStyle,
+    text_style::{TextOverflow, TextStyle},
+    Formatter,
+};
 
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -85,6 +95,38 @@ pub struct Label {
     shadow_offset_x: Option<f64>,
 
     shadow_offset_y: Option<f64>,
+
+    value_animation: Option<bool>,
+
+    height: Option<f64>,
+
+    font_style: Option<String>,
+
+    font_family: Option<String>,
+
+    line_height: Option<f64>,
+
+    width: Option<CompositeValue>,
+
+    border_type: Option<BorderType>,
+
+    border_radius: Option<f64>,
+
+    shadow_color: Option<Color>,
+
+    text_border_color: Option<Color>,
+
+    text_border_width: Option<f64>,
+
+    text_shadow_color: Option<Color>,
+
+    text_shadow_blur: Option<f64>,
+
+    overflow: Option<TextOverflow>,
+
+    ellipsis: Option<String>,
+
+    rich: Option<BTreeMap<String, TextStyle>>,
 }
 
 impl Label {
@@ -109,6 +151,22 @@ impl Label {
             shadow_blur: None,
             shadow_offset_x: None,
             shadow_offset_y: None,
+            value_animation: None,
+            height: None,
+            font_style: None,
+            font_family: None,
+            line_height: None,
+            width: None,
+            border_type: None,
+            border_radius: None,
+            shadow_color: None,
+            text_border_color: None,
+            text_border_width: None,
+            text_shadow_color: None,
+            text_shadow_blur: None,
+            overflow: None,
+            ellipsis: None,
+            rich: None,
         }
     }
 
@@ -211,6 +269,93 @@ impl Label {
         self.shadow_offset_y = Some(shadow_offset_y.into());
         self
     }
+
+    /// Whether to animate the label text when the value changes.
+    pub fn value_animation(mut self, value_animation: bool) -> Self {
+        self.value_animation = Some(value_animation);
+        self
+    }
+
+    /// Height of the label box, e.g. the height of a treemap upper label.
+    pub fn height<F: Into<f64>>(mut self, height: F) -> Self {
+        self.height = Some(height.into());
+        self
+    }
+
+    pub fn font_style<S: Into<String>>(mut self, font_style: S) -> Self {
+        self.font_style = Some(font_style.into());
+        self
+    }
+
+    pub fn font_family<S: Into<String>>(mut self, font_family: S) -> Self {
+        self.font_family = Some(font_family.into());
+        self
+    }
+
+    pub fn line_height<F: Into<f64>>(mut self, line_height: F) -> Self {
+        self.line_height = Some(line_height.into());
+        self
+    }
+
+    /// Width of the label box. Required for `overflow` to take effect.
+    pub fn width<C: Into<CompositeValue>>(mut self, width: C) -> Self {
+        self.width = Some(width.into());
+        self
+    }
+
+    pub fn border_type<B: Into<BorderType>>(mut self, border_type: B) -> Self {
+        self.border_type = Some(border_type.into());
+        self
+    }
+
+    pub fn border_radius<F: Into<f64>>(mut self, border_radius: F) -> Self {
+        self.border_radius = Some(border_radius.into());
+        self
+    }
+
+    pub fn shadow_color<C: Into<Color>>(mut self, shadow_color: C) -> Self {
+        self.shadow_color = Some(shadow_color.into());
+        self
+    }
+
+    pub fn text_border_color<C: Into<Color>>(mut self, text_border_color: C) -> Self {
+        self.text_border_color = Some(text_border_color.into());
+        self
+    }
+
+    pub fn text_border_width<F: Into<f64>>(mut self, text_border_width: F) -> Self {
+        self.text_border_width = Some(text_border_width.into());
+        self
+    }
+
+    pub fn text_shadow_color<C: Into<Color>>(mut self, text_shadow_color: C) -> Self {
+        self.text_shadow_color = Some(text_shadow_color.into());
+        self
+    }
+
+    pub fn text_shadow_blur<F: Into<f64>>(mut self, text_shadow_blur: F) -> Self {
+        self.text_shadow_blur = Some(text_shadow_blur.into());
+        self
+    }
+
+    pub fn overflow<O: Into<TextOverflow>>(mut self, overflow: O) -> Self {
+        self.overflow = Some(overflow.into());
+        self
+    }
+
+    pub fn ellipsis<S: Into<String>>(mut self, ellipsis: S) -> Self {
+        self.ellipsis = Some(ellipsis.into());
+        self
+    }
+
+    /// Add a named rich text style, referenced from the formatter as
+    /// `{name|text}`.
+    pub fn rich<S: Into<String>, T: Into<TextStyle>>(mut self, name: S, style: T) -> Self {
+        self.rich
+            .get_or_insert_with(BTreeMap::new)
+            .insert(name.into(), style.into());
+        self
+    }
 }
 
 #[serde_auto]
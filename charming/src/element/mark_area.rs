@@ -1,42 +1,140 @@
 use serde::{Deserialize, Serialize};
 use macros::serde_auto;
 
-use super::{blur::Blur, emphasis::Emphasis, item_style::ItemStyle, label::Label};
+use crate::datatype::CompositeValue;
 
+use super::{
+    blur::Blur, emphasis::Emphasis, impl_animation, item_style::ItemStyle, label::Label,
+    mark_line::MarkLineDataType, Animation,
+};
+
+/// One corner of a mark area. Styles set on the first corner apply to the
+/// whole area.
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MarkAreaData {
+    #[serde(rename = "type")]
+    type_: Option<MarkLineDataType>,
+
     name: Option<String>,
 
-    x_axis: Option<String>,
+    value_index: Option<f64>,
+
+    value_dim: Option<String>,
+
+    coord: Option<CompositeValue>,
+
+    x: Option<CompositeValue>,
+
+    y: Option<CompositeValue>,
+
+    x_axis: Option<CompositeValue>,
+
+    y_axis: Option<CompositeValue>,
+
+    value: Option<CompositeValue>,
 
-    y_axis: Option<String>,
+    item_style: Option<ItemStyle>,
+
+    label: Option<Label>,
+
+    emphasis: Option<Emphasis>,
+
+    blur: Option<Blur>,
 }
 
 impl MarkAreaData {
     pub fn new() -> Self {
         Self {
+            type_: None,
             name: None,
+            value_index: None,
+            value_dim: None,
+            coord: None,
+            x: None,
+            y: None,
             x_axis: None,
             y_axis: None,
+            value: None,
+            item_style: None,
+            label: None,
+            emphasis: None,
+            blur: None,
         }
     }
 
+    pub fn type_<T: Into<MarkLineDataType>>(mut self, type_: T) -> Self {
+        self.type_ = Some(type_.into());
+        self
+    }
+
     pub fn name<S: Into<String>>(mut self, name: S) -> Self {
         self.name = Some(name.into());
         self
     }
 
-    pub fn x_axis<F: Into<String>>(mut self, x_axis: F) -> Self {
+    /// Index of the dimension the statistical `type_` is computed on.
+    pub fn value_index<F: Into<f64>>(mut self, value_index: F) -> Self {
+        self.value_index = Some(value_index.into());
+        self
+    }
+
+    /// Name of the dimension the statistical `type_` is computed on.
+    pub fn value_dim<S: Into<String>>(mut self, value_dim: S) -> Self {
+        self.value_dim = Some(value_dim.into());
+        self
+    }
+
+    pub fn coord<C: Into<CompositeValue>>(mut self, coord: C) -> Self {
+        self.coord = Some(coord.into());
+        self
+    }
+
+    pub fn x<C: Into<CompositeValue>>(mut self, x: C) -> Self {
+        self.x = Some(x.into());
+        self
+    }
+
+    pub fn y<C: Into<CompositeValue>>(mut self, y: C) -> Self {
+        self.y = Some(y.into());
+        self
+    }
+
+    pub fn x_axis<C: Into<CompositeValue>>(mut self, x_axis: C) -> Self {
         self.x_axis = Some(x_axis.into());
         self
     }
 
-    pub fn y_axis<F: Into<String>>(mut self, y_axis: F) -> Self {
+    pub fn y_axis<C: Into<CompositeValue>>(mut self, y_axis: C) -> Self {
         self.y_axis = Some(y_axis.into());
         self
     }
+
+    pub fn value<C: Into<CompositeValue>>(mut self, value: C) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    pub fn item_style<S: Into<ItemStyle>>(mut self, item_style: S) -> Self {
+        self.item_style = Some(item_style.into());
+        self
+    }
+
+    pub fn label<L: Into<Label>>(mut self, label: L) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn emphasis<E: Into<Emphasis>>(mut self, emphasis: E) -> Self {
+        self.emphasis = Some(emphasis.into());
+        self
+    }
+
+    pub fn blur<B: Into<Blur>>(mut self, blur: B) -> Self {
+        self.blur = Some(blur.into());
+        self
+    }
 }
 
 #[serde_auto]
@@ -53,6 +151,12 @@ pub struct MarkArea {
 
     blur: Option<Blur>,
 
+    zlevel: Option<f64>,
+
+    z: Option<f64>,
+
+    #[serde(flatten)]
+    animation: Animation,
 
     data: Vec<(MarkAreaData, MarkAreaData)>,
 }
@@ -65,6 +169,9 @@ impl MarkArea {
             item_style: None,
             emphasis: None,
             blur: None,
+            zlevel: None,
+            z: None,
+            animation: Animation::new(),
             data: vec![],
         }
     }
@@ -94,6 +201,16 @@ impl MarkArea {
         self
     }
 
+    pub fn zlevel<F: Into<f64>>(mut self, zlevel: F) -> Self {
+        self.zlevel = Some(zlevel.into());
+        self
+    }
+
+    pub fn z<F: Into<f64>>(mut self, z: F) -> Self {
+        self.z = Some(z.into());
+        self
+    }
+
     pub fn data<D: Into<MarkAreaData>>(mut self, data: Vec<(D, D)>) -> Self {
         self.data = data
             .into_iter()
@@ -102,3 +219,5 @@ impl MarkArea {
         self
     }
 }
+
+impl_animation!(MarkArea);
@@ -3,7 +3,10 @@ use macros::serde_auto;
 
 use crate::datatype::CompositeValue;
 
-use super::{label::Label, line_style::LineStyle, symbol::Symbol};
+use super::{
+    blur::Blur, emphasis::Emphasis, impl_animation, label::Label, line_style::LineStyle,
+    symbol::Symbol, symbol_size::SymbolSize, Animation,
+};
 
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -36,8 +39,18 @@ pub struct MarkLineData {
 
     name: Option<String>,
 
+    value_index: Option<f64>,
+
+    value_dim: Option<String>,
+
     symbol: Option<Symbol>,
 
+    symbol_size: Option<SymbolSize>,
+
+    symbol_rotate: Option<f64>,
+
+    symbol_offset: Option<(CompositeValue, CompositeValue)>,
+
     x: Option<CompositeValue>,
 
     y: Option<CompositeValue>,
@@ -48,7 +61,15 @@ pub struct MarkLineData {
 
     coord: Option<CompositeValue>,
 
+    value: Option<CompositeValue>,
+
+    line_style: Option<LineStyle>,
+
     label: Option<Label>,
+
+    emphasis: Option<Emphasis>,
+
+    blur: Option<Blur>,
 }
 
 impl MarkLineData {
@@ -56,13 +77,22 @@ impl MarkLineData {
         Self {
             type_: None,
             name: None,
+            value_index: None,
+            value_dim: None,
             symbol: None,
+            symbol_size: None,
+            symbol_rotate: None,
+            symbol_offset: None,
             x: None,
             y: None,
             x_axis: None,
             y_axis: None,
             coord: None,
+            value: None,
+            line_style: None,
             label: None,
+            emphasis: None,
+            blur: None,
         }
     }
 
@@ -76,11 +106,38 @@ impl MarkLineData {
         self
     }
 
+    /// Index of the dimension the statistical `type_` is computed on.
+    pub fn value_index<F: Into<f64>>(mut self, value_index: F) -> Self {
+        self.value_index = Some(value_index.into());
+        self
+    }
+
+    /// Name of the dimension the statistical `type_` is computed on.
+    pub fn value_dim<S: Into<String>>(mut self, value_dim: S) -> Self {
+        self.value_dim = Some(value_dim.into());
+        self
+    }
+
     pub fn symbol(mut self, symbol: Symbol) -> Self {
         self.symbol = Some(symbol);
         self
     }
 
+    pub fn symbol_size<S: Into<SymbolSize>>(mut self, symbol_size: S) -> Self {
+        self.symbol_size = Some(symbol_size.into());
+        self
+    }
+
+    pub fn symbol_rotate<F: Into<f64>>(mut self, symbol_rotate: F) -> Self {
+        self.symbol_rotate = Some(symbol_rotate.into());
+        self
+    }
+
+    pub fn symbol_offset<C: Into<CompositeValue>>(mut self, symbol_offset: (C, C)) -> Self {
+        self.symbol_offset = Some((symbol_offset.0.into(), symbol_offset.1.into()));
+        self
+    }
+
     pub fn x<C: Into<CompositeValue>>(mut self, x: C) -> Self {
         self.x = Some(x.into());
         self
@@ -106,10 +163,30 @@ impl MarkLineData {
         self
     }
 
+    pub fn value<V: Into<CompositeValue>>(mut self, value: V) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    pub fn line_style<L: Into<LineStyle>>(mut self, line_style: L) -> Self {
+        self.line_style = Some(line_style.into());
+        self
+    }
+
     pub fn label(mut self, label: Label) -> Self {
         self.label = Some(label);
         self
     }
+
+    pub fn emphasis<E: Into<Emphasis>>(mut self, emphasis: E) -> Self {
+        self.emphasis = Some(emphasis.into());
+        self
+    }
+
+    pub fn blur<B: Into<Blur>>(mut self, blur: B) -> Self {
+        self.blur = Some(blur.into());
+        self
+    }
 }
 
 impl From<(&str, &str)> for MarkLineData {
@@ -143,17 +220,30 @@ impl Serialize for MarkLineVariant {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MarkLine {
+    silent: Option<bool>,
+
+    symbol: Vec<Symbol>,
+
+    symbol_size: Option<SymbolSize>,
+
+    symbol_offset: Option<(CompositeValue, CompositeValue)>,
+
+    precision: Option<f64>,
+
     label: Option<Label>,
 
     line_style: Option<LineStyle>,
 
-    zlevel: Option<f64>,
+    emphasis: Option<Emphasis>,
 
-    z: Option<f64>,
+    blur: Option<Blur>,
 
+    zlevel: Option<f64>,
 
-    symbol: Vec<Symbol>,
+    z: Option<f64>,
 
+    #[serde(flatten)]
+    animation: Animation,
 
     data: Vec<MarkLineVariant>,
 }
@@ -161,15 +251,50 @@ pub struct MarkLine {
 impl MarkLine {
     pub fn new() -> Self {
         Self {
+            silent: None,
+            symbol: vec![],
+            symbol_size: None,
+            symbol_offset: None,
+            precision: None,
             label: None,
             line_style: None,
+            emphasis: None,
+            blur: None,
             zlevel: None,
             z: None,
-            symbol: vec![],
+            animation: Animation::new(),
             data: vec![],
         }
     }
 
+    /// Whether the mark lines ignore mouse events.
+    pub fn silent(mut self, silent: bool) -> Self {
+        self.silent = Some(silent);
+        self
+    }
+
+    /// Symbols at the start and end of the lines.
+    pub fn symbol<S: Into<Symbol>>(mut self, symbol: Vec<S>) -> Self {
+        self.symbol = symbol.into_iter().map(|s| s.into()).collect();
+        self
+    }
+
+    pub fn symbol_size<S: Into<SymbolSize>>(mut self, symbol_size: S) -> Self {
+        self.symbol_size = Some(symbol_size.into());
+        self
+    }
+
+    pub fn symbol_offset<C: Into<CompositeValue>>(mut self, symbol_offset: (C, C)) -> Self {
+        self.symbol_offset = Some((symbol_offset.0.into(), symbol_offset.1.into()));
+        self
+    }
+
+    /// Number of decimal places of the values shown in the labels.
+    pub fn precision<F: Into<f64>>(mut self, precision: F) -> Self {
+        self.precision = Some(precision.into());
+        self
+    }
+
     pub fn label<L: Into<Label>>(mut self, label: L) -> Self {
         self.label = Some(label.into());
         self
@@ -180,6 +305,16 @@ impl MarkLine {
         self
     }
 
+    pub fn emphasis<E: Into<Emphasis>>(mut self, emphasis: E) -> Self {
+        self.emphasis = Some(emphasis.into());
+        self
+    }
+
+    pub fn blur<B: Into<Blur>>(mut self, blur: B) -> Self {
+        self.blur = Some(blur.into());
+        self
+    }
+
     pub fn zlevel<F: Into<f64>>(mut self, zlevel: F) -> Self {
         self.zlevel = Some(zlevel.into());
         self
@@ -190,13 +325,10 @@ impl MarkLine {
         self
     }
 
-    pub fn symbol<S: Into<Symbol>>(mut self, symbol: Vec<S>) -> Self {
-        self.symbol = symbol.into_iter().map(|s| s.into()).collect();
-        self
-    }
-
     pub fn data<M: Into<MarkLineVariant>>(mut self, data: Vec<M>) -> Self {
         self.data = data.into_iter().map(|m| m.into()).collect();
         self
     }
 }
+
+impl_animation!(MarkLine);
@@ -1,6 +1,13 @@
 use serde::{Deserialize, Serialize};
 use macros::serde_auto;
 
+use crate::datatype::CompositeValue;
+
+use super::{
+    blur::Blur, emphasis::Emphasis, impl_animation, item_style::ItemStyle, label::Label,
+    symbol::Symbol, symbol_size::SymbolSize, Animation,
+};
+
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -8,6 +15,7 @@ pub enum MarkPointDataType {
     Min,
     Max,
     Average,
+    Median,
 }
 
 impl From<&str> for MarkPointDataType {
@@ -16,11 +24,15 @@ impl From<&str> for MarkPointDataType {
             "min" => Self::Min,
             "max" => Self::Max,
             "avg" | "average" => Self::Average,
+            "med" | "median" => Self::Median,
             _ => panic!("Invalid MarkPointDataType"),
         }
     }
 }
 
+/// A single mark point. It is placed either by a statistical `type_`, by
+/// `coord` in the coordinate system, by `x`/`y` in pixels relative to the
+/// container, or by `x_axis`/`y_axis` values.
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -30,11 +42,39 @@ pub struct MarkPointData {
 
     name: Option<String>,
 
-    x_axis: Option<f64>,
+    value_index: Option<f64>,
+
+    value_dim: Option<String>,
+
+    coord: Option<CompositeValue>,
+
+    x: Option<CompositeValue>,
+
+    y: Option<CompositeValue>,
+
+    x_axis: Option<CompositeValue>,
+
+    y_axis: Option<CompositeValue>,
+
+    value: Option<CompositeValue>,
+
+    symbol: Option<Symbol>,
+
+    symbol_size: Option<SymbolSize>,
+
+    symbol_rotate: Option<f64>,
+
+    symbol_keep_aspect: Option<bool>,
+
+    symbol_offset: Option<(CompositeValue, CompositeValue)>,
 
-    y_axis: Option<f64>,
+    item_style: Option<ItemStyle>,
 
-    value: Option<f64>,
+    label: Option<Label>,
+
+    emphasis: Option<Emphasis>,
+
+    blur: Option<Blur>,
 }
 
 impl MarkPointData {
@@ -42,9 +82,23 @@ impl MarkPointData {
         Self {
             type_: None,
             name: None,
+            value_index: None,
+            value_dim: None,
+            coord: None,
+            x: None,
+            y: None,
             x_axis: None,
             y_axis: None,
             value: None,
+            symbol: None,
+            symbol_size: None,
+            symbol_rotate: None,
+            symbol_keep_aspect: None,
+            symbol_offset: None,
+            item_style: None,
+            label: None,
+            emphasis: None,
+            blur: None,
         }
     }
 
@@ -58,20 +112,92 @@ impl MarkPointData {
         self
     }
 
-    pub fn x_axis<F: Into<f64>>(mut self, x_axis: F) -> Self {
+    /// Index of the dimension the statistical `type_` is computed on.
+    pub fn value_index<F: Into<f64>>(mut self, value_index: F) -> Self {
+        self.value_index = Some(value_index.into());
+        self
+    }
+
+    /// Name of the dimension the statistical `type_` is computed on.
+    pub fn value_dim<S: Into<String>>(mut self, value_dim: S) -> Self {
+        self.value_dim = Some(value_dim.into());
+        self
+    }
+
+    pub fn coord<C: Into<CompositeValue>>(mut self, coord: C) -> Self {
+        self.coord = Some(coord.into());
+        self
+    }
+
+    pub fn x<C: Into<CompositeValue>>(mut self, x: C) -> Self {
+        self.x = Some(x.into());
+        self
+    }
+
+    pub fn y<C: Into<CompositeValue>>(mut self, y: C) -> Self {
+        self.y = Some(y.into());
+        self
+    }
+
+    pub fn x_axis<C: Into<CompositeValue>>(mut self, x_axis: C) -> Self {
         self.x_axis = Some(x_axis.into());
         self
     }
 
-    pub fn y_axis<F: Into<f64>>(mut self, y_axis: F) -> Self {
+    pub fn y_axis<C: Into<CompositeValue>>(mut self, y_axis: C) -> Self {
         self.y_axis = Some(y_axis.into());
         self
     }
 
-    pub fn value<F: Into<f64>>(mut self, value: F) -> Self {
+    pub fn value<C: Into<CompositeValue>>(mut self, value: C) -> Self {
         self.value = Some(value.into());
         self
     }
+
+    pub fn symbol<S: Into<Symbol>>(mut self, symbol: S) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+
+    pub fn symbol_size<S: Into<SymbolSize>>(mut self, symbol_size: S) -> Self {
+        self.symbol_size = Some(symbol_size.into());
+        self
+    }
+
+    pub fn symbol_rotate<F: Into<f64>>(mut self, symbol_rotate: F) -> Self {
+        self.symbol_rotate = Some(symbol_rotate.into());
+        self
+    }
+
+    pub fn symbol_keep_aspect(mut self, symbol_keep_aspect: bool) -> Self {
+        self.symbol_keep_aspect = Some(symbol_keep_aspect);
+        self
+    }
+
+    pub fn symbol_offset<C: Into<CompositeValue>>(mut self, symbol_offset: (C, C)) -> Self {
+        self.symbol_offset = Some((symbol_offset.0.into(), symbol_offset.1.into()));
+        self
+    }
+
+    pub fn item_style<S: Into<ItemStyle>>(mut self, item_style: S) -> Self {
+        self.item_style = Some(item_style.into());
+        self
+    }
+
+    pub fn label<L: Into<Label>>(mut self, label: L) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn emphasis<E: Into<Emphasis>>(mut self, emphasis: E) -> Self {
+        self.emphasis = Some(emphasis.into());
+        self
+    }
+
+    pub fn blur<B: Into<Blur>>(mut self, blur: B) -> Self {
+        self.blur = Some(blur.into());
+        self
+    }
 }
 
 impl From<(&str, &str)> for MarkPointData {
@@ -84,13 +210,99 @@ impl From<(&str, &str)> for MarkPointData {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MarkPoint {
+    symbol: Option<Symbol>,
+
+    symbol_size: Option<SymbolSize>,
+
+    symbol_rotate: Option<f64>,
+
+    symbol_keep_aspect: Option<bool>,
+
+    symbol_offset: Option<(CompositeValue, CompositeValue)>,
+
+    silent: Option<bool>,
+
+    label: Option<Label>,
+
+    item_style: Option<ItemStyle>,
+
+    emphasis: Option<Emphasis>,
+
+    blur: Option<Blur>,
+
+    #[serde(flatten)]
+    animation: Animation,
 
     data: Vec<MarkPointData>,
 }
 
 impl MarkPoint {
     pub fn new() -> Self {
-        Self { data: vec![] }
+        Self {
+            symbol: None,
+            symbol_size: None,
+            symbol_rotate: None,
+            symbol_keep_aspect: None,
+            symbol_offset: None,
+            silent: None,
+            label: None,
+            item_style: None,
+            emphasis: None,
+            blur: None,
+            animation: Animation::new(),
+            data: vec![],
+        }
+    }
+
+    pub fn symbol<S: Into<Symbol>>(mut self, symbol: S) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+
+    pub fn symbol_size<S: Into<SymbolSize>>(mut self, symbol_size: S) -> Self {
+        self.symbol_size = Some(symbol_size.into());
+        self
+    }
+
+    pub fn symbol_rotate<F: Into<f64>>(mut self, symbol_rotate: F) -> Self {
+        self.symbol_rotate = Some(symbol_rotate.into());
+        self
+    }
+
+    pub fn symbol_keep_aspect(mut self, symbol_keep_aspect: bool) -> Self {
+        self.symbol_keep_aspect = Some(symbol_keep_aspect);
+        self
+    }
+
+    pub fn symbol_offset<C: Into<CompositeValue>>(mut self, symbol_offset: (C, C)) -> Self {
+        self.symbol_offset = Some((symbol_offset.0.into(), symbol_offset.1.into()));
+        self
+    }
+
+    /// Whether the mark points ignore mouse events.
+    pub fn silent(mut self, silent: bool) -> Self {
+        self.silent = Some(silent);
+        self
+    }
+
+    pub fn label<L: Into<Label>>(mut self, label: L) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn item_style<S: Into<ItemStyle>>(mut self, item_style: S) -> Self {
+        self.item_style = Some(item_style.into());
+        self
+    }
+
+    pub fn emphasis<E: Into<Emphasis>>(mut self, emphasis: E) -> Self {
+        self.emphasis = Some(emphasis.into());
+        self
+    }
+
+    pub fn blur<B: Into<Blur>>(mut self, blur: B) -> Self {
+        self.blur = Some(blur.into());
+        self
     }
 
     pub fn data<D: Into<MarkPointData>>(mut self, data: Vec<D>) -> Self {
@@ -98,3 +310,40 @@ impl MarkPoint {
         self
     }
 }
+
+impl_animation!(MarkPoint);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn mark_point_on_dimension() {
+        let mark_point = MarkPoint::new().symbol(Symbol::Pin).silent(true).data(vec![
+            MarkPointData::new()
+                .type_(MarkPointDataType::Median)
+                .value_dim("latency"),
+            MarkPointData::new()
+                .name("incident")
+                .coord(vec!["10:00", "420"])
+                .symbol_size(60.0)
+                .item_style(ItemStyle::new().color("red")),
+        ]);
+        assert_eq!(
+            serde_json::to_value(&mark_point).unwrap(),
+            serde_json::json!({
+                "symbol": "pin",
+                "silent": true,
+                "data": [
+                    { "type": "median", "valueDim": "latency" },
+                    {
+                        "name": "incident",
+                        "coord": ["10:00", "420"],
+                        "symbolSize": 60.0,
+                        "itemStyle": { "color": "red" },
+                    },
+                ],
+            })
+        );
+    }
+}
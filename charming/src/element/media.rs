@@ -0,0 +1,158 @@
+use serde::{Deserialize, Serialize};
+use macros::serde_auto;
+
+use crate::Chart;
+
+/// Condition on the size of the chart container. All conditions set on a
+/// query must hold for it to match.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaQuery {
+    width: Option<f64>,
+
+    min_width: Option<f64>,
+
+    max_width: Option<f64>,
+
+    height: Option<f64>,
+
+    min_height: Option<f64>,
+
+    max_height: Option<f64>,
+
+    aspect_ratio: Option<f64>,
+
+    min_aspect_ratio: Option<f64>,
+
+    max_aspect_ratio: Option<f64>,
+}
+
+impl MediaQuery {
+    pub fn new() -> Self {
+        Self {
+            width: None,
+            min_width: None,
+            max_width: None,
+            height: None,
+            min_height: None,
+            max_height: None,
+            aspect_ratio: None,
+            min_aspect_ratio: None,
+            max_aspect_ratio: None,
+        }
+    }
+
+    pub fn width<F: Into<f64>>(mut self, width: F) -> Self {
+        self.width = Some(width.into());
+        self
+    }
+
+    pub fn min_width<F: Into<f64>>(mut self, min_width: F) -> Self {
+        self.min_width = Some(min_width.into());
+        self
+    }
+
+    pub fn max_width<F: Into<f64>>(mut self, max_width: F) -> Self {
+        self.max_width = Some(max_width.into());
+        self
+    }
+
+    pub fn height<F: Into<f64>>(mut self, height: F) -> Self {
+        self.height = Some(height.into());
+        self
+    }
+
+    pub fn min_height<F: Into<f64>>(mut self, min_height: F) -> Self {
+        self.min_height = Some(min_height.into());
+        self
+    }
+
+    pub fn max_height<F: Into<f64>>(mut self, max_height: F) -> Self {
+        self.max_height = Some(max_height.into());
+        self
+    }
+
+    /// Width divided by height of the container.
+    pub fn aspect_ratio<F: Into<f64>>(mut self, aspect_ratio: F) -> Self {
+        self.aspect_ratio = Some(aspect_ratio.into());
+        self
+    }
+
+    pub fn min_aspect_ratio<F: Into<f64>>(mut self, min_aspect_ratio: F) -> Self {
+        self.min_aspect_ratio = Some(min_aspect_ratio.into());
+        self
+    }
+
+    pub fn max_aspect_ratio<F: Into<f64>>(mut self, max_aspect_ratio: F) -> Self {
+        self.max_aspect_ratio = Some(max_aspect_ratio.into());
+        self
+    }
+}
+
+/// A partial chart option that is merged into the chart while its query
+/// matches. A media without a query is applied when no other query
+/// matches.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Media {
+    query: Option<MediaQuery>,
+
+    option: Option<Chart>,
+}
+
+impl Media {
+    pub fn new() -> Self {
+        Self {
+            query: None,
+            option: None,
+        }
+    }
+
+    pub fn query<Q: Into<MediaQuery>>(mut self, query: Q) -> Self {
+        self.query = Some(query.into());
+        self
+    }
+
+    /// The overrides to apply. Components and series are merged with those
+    /// of the chart by index, so only the changed options need to be set.
+    pub fn option<C: Into<Chart>>(mut self, option: C) -> Self {
+        self.option = Some(option.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::component::{Grid, Legend};
+
+    use super::*;
+
+    #[test]
+    fn responsive_legend() {
+        let chart = Chart::new()
+            .legend(Legend::new().top("top"))
+            .media(
+                Media::new().query(MediaQuery::new().max_width(500)).option(
+                    Chart::new()
+                        .legend(Legend::new().top("bottom"))
+                        .grid(Grid::new().bottom(80)),
+                ),
+            )
+            .media(Media::new().option(Chart::new().legend(Legend::new().top("top"))));
+        assert_eq!(
+            serde_json::to_value(&chart).unwrap()["media"],
+            serde_json::json!([
+                {
+                    "query": { "maxWidth": 500.0 },
+                    "option": {
+                        "legend": { "top": "bottom" },
+                        "grid": [{ "bottom": 80 }],
+                    },
+                },
+                { "option": { "legend": { "top": "top" } } },
+            ])
+        );
+    }
+}
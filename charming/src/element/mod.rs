@@ -1,6 +1,7 @@
 pub(crate) mod raw_string;
 
 pub mod anchor;
+pub mod animation;
 pub mod area_style;
 pub mod axis_label;
 pub mod axis_line;
@@ -13,7 +14,6 @@ pub mod border_type;
 pub mod boundary_gap;
 pub mod color;
 pub mod coordinate;
-pub mod coordinate_tooltip;
 pub mod cursor;
 pub mod dimension_encode;
 pub mod emphasis;
@@ -26,6 +26,7 @@ pub mod link_target;
 pub mod mark_area;
 pub mod mark_line;
 pub mod mark_point;
+pub mod media;
 pub mod minor_split_line;
 pub mod minor_tick;
 pub mod name_location;
@@ -33,8 +34,11 @@ pub mod orient;
 pub mod padding;
 pub mod parallel_layout;
 pub mod pointer;
+pub mod render_item;
+pub mod sampling;
 pub mod scale_limit;
 pub mod select;
+pub mod series_layout;
 pub mod shape;
 pub mod sort;
 pub mod split_area;
@@ -48,6 +52,7 @@ pub mod tooltip;
 pub(crate) use raw_string::*;
 
 pub use anchor::*;
+pub use animation::*;
 pub use area_style::*;
 pub use axis_label::*;
 pub use axis_line::*;
@@ -60,7 +65,6 @@ pub use border_type::*;
 pub use boundary_gap::*;
 pub use color::*;
 pub use coordinate::*;
-pub use coordinate_tooltip::*;
 pub use cursor::*;
 pub use dimension_encode::*;
 pub use emphasis::*;
@@ -73,6 +77,7 @@ pub use link_target::*;
 pub use mark_area::*;
 pub use mark_line::*;
 pub use mark_point::*;
+pub use media::*;
 pub use minor_split_line::*;
 pub use minor_tick::*;
 pub use name_location::*;
@@ -80,8 +85,11 @@ pub use orient::*;
 pub use padding::*;
 pub use parallel_layout::*;
 pub use pointer::*;
+pub use render_item::*;
+pub use sampling::*;
 pub use scale_limit::*;
 pub use select::*;
+pub use series_layout::*;
 pub use shape::*;
 pub use sort::*;
 pub use split_area::*;
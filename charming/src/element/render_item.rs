@@ -0,0 +1,412 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use super::RawString;
+
+/// A JavaScript expression evaluated for every data item inside the
+/// `renderItem` function of a [`Custom`] series.
+///
+/// Expressions are combined with the usual arithmetic operators, e.g.
+/// `RenderExpr::value(1) * 0.5`.
+///
+/// [`Custom`]: crate::series::Custom
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderExpr(String);
+
+impl RenderExpr {
+    /// The value of the given dimension of the current data item,
+    /// `api.value(dim)`.
+    pub fn value(dim: usize) -> Self {
+        RenderExpr(format!("api.value({})", dim))
+    }
+
+    /// The value of the named dimension of the current data item.
+    pub fn value_of<S: AsRef<str>>(dim: S) -> Self {
+        RenderExpr(format!("api.value({})", js_string(dim.as_ref())))
+    }
+
+    /// A visual property of the current data item, e.g. `"color"`.
+    pub fn visual<S: AsRef<str>>(name: S) -> Self {
+        RenderExpr(format!("api.visual({})", js_string(name.as_ref())))
+    }
+
+    /// The index of the current data item.
+    pub fn data_index() -> Self {
+        RenderExpr("params.dataIndex".to_string())
+    }
+
+    /// A verbatim JavaScript expression, which may use `params` and `api`.
+    pub fn raw<S: Into<String>>(js: S) -> Self {
+        RenderExpr(js.into())
+    }
+}
+
+impl From<f64> for RenderExpr {
+    fn from(n: f64) -> Self {
+        if n.is_infinite() {
+            RenderExpr(if n > 0.0 { "Infinity" } else { "-Infinity" }.to_string())
+        } else {
+            RenderExpr(n.to_string())
+        }
+    }
+}
+
+impl From<i32> for RenderExpr {
+    fn from(n: i32) -> Self {
+        RenderExpr(n.to_string())
+    }
+}
+
+/// A string literal, e.g. a color or a text.
+impl From<&str> for RenderExpr {
+    fn from(s: &str) -> Self {
+        RenderExpr(js_string(s))
+    }
+}
+
+macro_rules! impl_render_expr_op {
+    ($trait:ident, $method:ident, $op:literal) => {
+        impl<E: Into<RenderExpr>> $trait<E> for RenderExpr {
+            type Output = RenderExpr;
+
+            fn $method(self, rhs: E) -> RenderExpr {
+                RenderExpr(format!("({} {} {})", self.0, $op, rhs.into().0))
+            }
+        }
+    };
+}
+
+impl_render_expr_op!(Add, add, "+");
+impl_render_expr_op!(Sub, sub, "-");
+impl_render_expr_op!(Mul, mul, "*");
+impl_render_expr_op!(Div, div, "/");
+
+impl Neg for RenderExpr {
+    type Output = RenderExpr;
+
+    fn neg(self) -> RenderExpr {
+        RenderExpr(format!("(-{})", self.0))
+    }
+}
+
+/// A point in pixels, such as a data value converted by the coordinate
+/// system.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderPoint(Point);
+
+#[derive(Debug, Clone, PartialEq)]
+enum Point {
+    Pair(RenderExpr, RenderExpr),
+    Array(String),
+}
+
+impl RenderPoint {
+    pub fn new<X: Into<RenderExpr>, Y: Into<RenderExpr>>(x: X, y: Y) -> Self {
+        RenderPoint(Point::Pair(x.into(), y.into()))
+    }
+
+    /// Converts a data value to a point, `api.coord([x, y])`.
+    pub fn coord<X: Into<RenderExpr>, Y: Into<RenderExpr>>(x: X, y: Y) -> Self {
+        RenderPoint(Point::Array(format!(
+            "api.coord([{}, {}])",
+            x.into().0,
+            y.into().0
+        )))
+    }
+
+    /// Converts a data range to a size in pixels, `api.size([dx, dy])`, e.g.
+    /// `RenderPoint::size(0, 1).y()` is the height of one category.
+    pub fn size<X: Into<RenderExpr>, Y: Into<RenderExpr>>(dx: X, dy: Y) -> Self {
+        RenderPoint(Point::Array(format!(
+            "api.size([{}, {}])",
+            dx.into().0,
+            dy.into().0
+        )))
+    }
+
+    pub fn x(&self) -> RenderExpr {
+        match &self.0 {
+            Point::Pair(x, _) => x.clone(),
+            Point::Array(a) => RenderExpr(format!("{}[0]", a)),
+        }
+    }
+
+    pub fn y(&self) -> RenderExpr {
+        match &self.0 {
+            Point::Pair(_, y) => y.clone(),
+            Point::Array(a) => RenderExpr(format!("{}[1]", a)),
+        }
+    }
+
+    fn to_js(&self) -> String {
+        match &self.0 {
+            Point::Pair(x, y) => format!("[{}, {}]", x.0, y.0),
+            Point::Array(a) => a.clone(),
+        }
+    }
+}
+
+/// A graphic element returned by `renderItem` for each data item.
+///
+/// ```
+/// use charming::element::{RenderExpr, RenderGraphic, RenderPoint};
+///
+/// // A Gantt bar from `[category, start, end]` data items.
+/// let start = RenderPoint::coord(RenderExpr::value(1), RenderExpr::value(0));
+/// let end = RenderPoint::coord(RenderExpr::value(2), RenderExpr::value(0));
+/// let height = RenderPoint::size(0, 1).y() * 0.6;
+/// let y = start.y() - height.clone() / 2;
+/// let bar = RenderGraphic::clipped_rect(start.x(), y, end.x() - start.x(), height)
+///     .fill(RenderExpr::visual("color"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderGraphic {
+    type_: &'static str,
+
+    props: Vec<(&'static str, String)>,
+
+    shape: Vec<(&'static str, String)>,
+
+    style: Vec<(String, String)>,
+
+    api_style: bool,
+
+    clip: bool,
+
+    children: Vec<RenderGraphic>,
+}
+
+impl RenderGraphic {
+    fn new(type_: &'static str) -> Self {
+        Self {
+            type_,
+            props: vec![],
+            shape: vec![],
+            style: vec![],
+            api_style: false,
+            clip: false,
+            children: vec![],
+        }
+    }
+
+    fn shape(mut self, key: &'static str, value: RenderExpr) -> Self {
+        self.shape.push((key, value.0));
+        self
+    }
+
+    pub fn rect<X, Y, W, H>(x: X, y: Y, width: W, height: H) -> Self
+    where
+        X: Into<RenderExpr>,
+        Y: Into<RenderExpr>,
+        W: Into<RenderExpr>,
+        H: Into<RenderExpr>,
+    {
+        Self::new("rect")
+            .shape("x", x.into())
+            .shape("y", y.into())
+            .shape("width", width.into())
+            .shape("height", height.into())
+    }
+
+    /// A rect clipped to the coordinate system, rendering nothing when it
+    /// falls entirely outside of it. Relies on the global `echarts` object.
+    pub fn clipped_rect<X, Y, W, H>(x: X, y: Y, width: W, height: H) -> Self
+    where
+        X: Into<RenderExpr>,
+        Y: Into<RenderExpr>,
+        W: Into<RenderExpr>,
+        H: Into<RenderExpr>,
+    {
+        let mut rect = Self::rect(x, y, width, height);
+        rect.clip = true;
+        rect
+    }
+
+    pub fn line(from: RenderPoint, to: RenderPoint) -> Self {
+        Self::new("line")
+            .shape("x1", from.x())
+            .shape("y1", from.y())
+            .shape("x2", to.x())
+            .shape("y2", to.y())
+    }
+
+    pub fn polygon(points: Vec<RenderPoint>) -> Self {
+        let points = points.iter().map(|p| p.to_js()).collect::<Vec<_>>();
+        let mut graphic = Self::new("polygon");
+        graphic
+            .shape
+            .push(("points", format!("[{}]", points.join(", "))));
+        graphic
+    }
+
+    pub fn circle<R: Into<RenderExpr>>(center: RenderPoint, r: R) -> Self {
+        Self::new("circle")
+            .shape("cx", center.x())
+            .shape("cy", center.y())
+            .shape("r", r.into())
+    }
+
+    /// A text placed at the given point. Its font and color are set with
+    /// [`RenderGraphic::style`].
+    pub fn text<T: Into<RenderExpr>>(position: RenderPoint, text: T) -> Self {
+        let mut graphic = Self::new("text");
+        graphic.props.push(("x", position.x().0));
+        graphic.props.push(("y", position.y().0));
+        graphic.style("text", text)
+    }
+
+    pub fn group(children: Vec<RenderGraphic>) -> Self {
+        let mut graphic = Self::new("group");
+        graphic.children = children;
+        graphic
+    }
+
+    /// Sets a style property, e.g. `"textAlign"`.
+    pub fn style<S: Into<String>, E: Into<RenderExpr>>(mut self, key: S, value: E) -> Self {
+        self.style.push((key.into(), value.into().0));
+        self
+    }
+
+    pub fn fill<E: Into<RenderExpr>>(self, fill: E) -> Self {
+        self.style("fill", fill)
+    }
+
+    pub fn stroke<E: Into<RenderExpr>>(self, stroke: E) -> Self {
+        self.style("stroke", stroke)
+    }
+
+    pub fn line_width<E: Into<RenderExpr>>(self, line_width: E) -> Self {
+        self.style("lineWidth", line_width)
+    }
+
+    /// Starts from the series style of the data item, `api.style()`, and
+    /// applies the other style properties on top of it.
+    pub fn api_style(mut self) -> Self {
+        self.api_style = true;
+        self
+    }
+
+    /// Drawing order of the element within its series.
+    pub fn z2<E: Into<RenderExpr>>(mut self, z2: E) -> Self {
+        self.props.push(("z2", z2.into().0));
+        self
+    }
+
+    fn to_js(&self) -> String {
+        let mut fields = vec![format!("type: '{}'", self.type_)];
+        fields.extend(self.props.iter().map(|(k, v)| format!("{}: {}", k, v)));
+        if !self.shape.is_empty() {
+            let shape = js_object(self.shape.iter().map(|(k, v)| (*k, v.as_str())));
+            if self.clip {
+                fields.push("shape: shape".to_string());
+            } else {
+                fields.push(format!("shape: {}", shape));
+            }
+        }
+        let style = js_object(self.style.iter().map(|(k, v)| (k.as_str(), v.as_str())));
+        if self.api_style {
+            fields.push(format!("style: api.style({})", style));
+        } else if !self.style.is_empty() {
+            fields.push(format!("style: {}", style));
+        }
+        if !self.children.is_empty() {
+            let children = self.children.iter().map(|c| c.to_js()).collect::<Vec<_>>();
+            fields.push(format!("children: [{}]", children.join(", ")));
+        }
+        let graphic = format!("{{{}}}", fields.join(", "));
+
+        if self.clip {
+            let shape = js_object(self.shape.iter().map(|(k, v)| (*k, v.as_str())));
+            format!(
+                "(function (shape) {{ return shape && {}; }})\
+                 (echarts.graphic.clipRectByRect({}, params.coordSys))",
+                graphic, shape
+            )
+        } else {
+            graphic
+        }
+    }
+
+    /// Compiles the graphic into a `renderItem` function.
+    pub fn to_render_item(&self) -> String {
+        format!("function (params, api) {{ return {}; }}", self.to_js())
+    }
+}
+
+impl From<RenderGraphic> for RawString {
+    fn from(graphic: RenderGraphic) -> Self {
+        RawString::from(graphic.to_render_item())
+    }
+}
+
+fn js_string(s: &str) -> String {
+    serde_json::to_string(s).unwrap()
+}
+
+fn js_object<'a, I: Iterator<Item = (&'a str, &'a str)>>(entries: I) -> String {
+    let entries = entries
+        .map(|(k, v)| format!("{}: {}", k, v))
+        .collect::<Vec<_>>();
+    format!("{{{}}}", entries.join(", "))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn render_error_bar() {
+        let x = RenderExpr::value(0);
+        let low = RenderPoint::coord(x.clone(), RenderExpr::value(1));
+        let high = RenderPoint::coord(x, RenderExpr::value(2));
+        let half_width = RenderPoint::size(1, 0).x() * 0.1;
+        let graphic = RenderGraphic::group(vec![
+            RenderGraphic::line(low.clone(), high).stroke(RenderExpr::visual("color")),
+            RenderGraphic::line(
+                RenderPoint::new(low.x() - half_width.clone(), low.y()),
+                RenderPoint::new(low.x() + half_width, low.y()),
+            )
+            .api_style(),
+        ]);
+        assert_eq!(
+            graphic.to_render_item(),
+            "function (params, api) { return {type: 'group', children: [\
+             {type: 'line', shape: {x1: api.coord([api.value(0), api.value(1)])[0], \
+             y1: api.coord([api.value(0), api.value(1)])[1], \
+             x2: api.coord([api.value(0), api.value(2)])[0], \
+             y2: api.coord([api.value(0), api.value(2)])[1]}, \
+             style: {stroke: api.visual(\"color\")}}, \
+             {type: 'line', shape: {x1: (api.coord([api.value(0), api.value(1)])[0] - (api.size([1, 0])[0] * 0.1)), \
+             y1: api.coord([api.value(0), api.value(1)])[1], \
+             x2: (api.coord([api.value(0), api.value(1)])[0] + (api.size([1, 0])[0] * 0.1)), \
+             y2: api.coord([api.value(0), api.value(1)])[1]}, \
+             style: api.style({})}]}; }"
+        );
+    }
+
+    #[test]
+    fn render_clipped_text() {
+        let graphic = RenderGraphic::group(vec![
+            RenderGraphic::clipped_rect(0, 0, RenderExpr::value(1), 10),
+            RenderGraphic::text(RenderPoint::new(5, 5), "label").fill("#fff"),
+        ]);
+        assert_eq!(
+            graphic.to_render_item(),
+            "function (params, api) { return {type: 'group', children: [\
+             (function (shape) { return shape && {type: 'rect', shape: shape}; })\
+             (echarts.graphic.clipRectByRect({x: 0, y: 0, width: api.value(1), height: 10}, params.coordSys)), \
+             {type: 'text', x: 5, y: 5, style: {text: \"label\", fill: \"#fff\"}}]}; }"
+        );
+    }
+
+    #[test]
+    fn render_infinite_number() {
+        assert_eq!(
+            RenderExpr::from(f64::INFINITY),
+            RenderExpr("Infinity".to_string())
+        );
+        assert_eq!(
+            RenderExpr::from(f64::NEG_INFINITY),
+            RenderExpr("-Infinity".to_string())
+        );
+    }
+}
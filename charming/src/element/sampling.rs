@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use macros::serde_auto;
+
+/// The down-sampling strategy used when the amount of data is much larger than
+/// the number of pixels.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Sampling {
+    /// Largest-Triangle-Three-Bucket, which keeps the trend of the data.
+    Lttb,
+    Average,
+    Min,
+    Max,
+    Minmax,
+    Sum,
+}
@@ -1,7 +1,12 @@
 use serde::{Deserialize, Serialize};
 use macros::serde_auto;
 
-use super::{item_style::ItemStyle, label::Label};
+use super::{
+    area_style::AreaStyle,
+    item_style::ItemStyle,
+    label::{Label, LabelLine},
+    line_style::LineStyle,
+};
 
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -9,9 +14,15 @@ use super::{item_style::ItemStyle, label::Label};
 pub struct Select {
     disabled: Option<bool>,
 
-    label: Option<Label>,
+    label: Option<Box<Label>>,
+
+    label_line: Option<LabelLine>,
 
     item_style: Option<ItemStyle>,
+
+    line_style: Option<LineStyle>,
+
+    area_style: Option<AreaStyle>,
 }
 
 impl Select {
@@ -19,7 +30,10 @@ impl Select {
         Self {
             disabled: None,
             label: None,
+            label_line: None,
             item_style: None,
+            line_style: None,
+            area_style: None,
         }
     }
 
@@ -29,7 +43,12 @@ impl Select {
     }
 
     pub fn label<L: Into<Label>>(mut self, label: L) -> Self {
-        self.label = Some(label.into());
+        self.label = Some(Box::new(label.into()));
+        self
+    }
+
+    pub fn label_line<L: Into<LabelLine>>(mut self, label_line: L) -> Self {
+        self.label_line = Some(label_line.into());
         self
     }
 
@@ -37,4 +56,49 @@ impl Select {
         self.item_style = Some(item_style.into());
         self
     }
+
+    pub fn line_style<S: Into<LineStyle>>(mut self, line_style: S) -> Self {
+        self.line_style = Some(line_style.into());
+        self
+    }
+
+    pub fn area_style<S: Into<AreaStyle>>(mut self, area_style: S) -> Self {
+        self.area_style = Some(area_style.into());
+        self
+    }
+}
+
+/// Whether and how data items can be selected by clicking.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub enum SelectedMode {
+    /// Selection is disabled.
+    Disabled,
+    /// Only one item can be selected at a time.
+    Single,
+    /// Multiple items can be selected.
+    Multiple,
+    /// Clicking an item selects the whole series.
+    Series,
+}
+
+impl Serialize for SelectedMode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            SelectedMode::Disabled => serializer.serialize_bool(false),
+            SelectedMode::Single => serializer.serialize_str("single"),
+            SelectedMode::Multiple => serializer.serialize_str("multiple"),
+            SelectedMode::Series => serializer.serialize_str("series"),
+        }
+    }
+}
+
+impl From<bool> for SelectedMode {
+    fn from(selected_mode: bool) -> Self {
+        if selected_mode {
+            SelectedMode::Single
+        } else {
+            SelectedMode::Disabled
+        }
+    }
 }
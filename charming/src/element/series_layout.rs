@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+use macros::serde_auto;
+
+/// Whether a series maps the columns or the rows of a dataset.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SeriesLayoutBy {
+    Column,
+    Row,
+}
@@ -11,6 +11,18 @@ pub enum SymbolSize {
     Function(RawString),
 }
 
+impl SymbolSize {
+    /// Sizes each symbol by the value of the given data dimension, e.g. the
+    /// third column of `[x, y, size]` items for a bubble chart. Use a JS
+    /// callback instead when the values need to be scaled.
+    pub fn dimension(index: usize) -> Self {
+        SymbolSize::Function(RawString::from(format!(
+            "function (value) {{ return value[{}]; }}",
+            index
+        )))
+    }
+}
+
 impl From<i64> for SymbolSize {
     fn from(n: i64) -> Self {
         SymbolSize::Number(n as f64)
@@ -1,8 +1,53 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 use macros::serde_auto;
 
-use super::color::Color;
+use crate::datatype::CompositeValue;
+
+use super::{border_type::BorderType, color::Color, padding::Padding};
+
+/// How to handle text that is wider than the `width` of its text box.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TextOverflow {
+    /// Truncate the text and append the `ellipsis` string.
+    Truncate,
+
+    /// Wrap the text at word boundaries.
+    Break,
+
+    /// Wrap the text anywhere, including inside words.
+    BreakAll,
+
+    /// Let the text overflow the box.
+    None,
+}
+
+/// Background of a text box, either a plain color or an image. An image
+/// background is how ECharts renders inline icons in rich text.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TextBackground {
+    /// An image URL, or a `data:` URI.
+    Image {
+        image: String,
+    },
+
+    Color(Color),
+}
+
+impl<C: Into<Color>> From<C> for TextBackground {
+    fn from(color: C) -> Self {
+        TextBackground::Color(color.into())
+    }
+}
 
+/// Style of a piece of text. Besides the plain font settings, a text style
+/// can carry a text box (background, border, padding, fixed size) and named
+/// `rich` styles, which formatters reference as `{name|text}`.
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -20,6 +65,56 @@ pub struct TextStyle {
     line_height: Option<f64>,
 
     align: Option<String>,
+
+    vertical_align: Option<String>,
+
+    background_color: Option<TextBackground>,
+
+    border_color: Option<Color>,
+
+    border_width: Option<f64>,
+
+    border_type: Option<BorderType>,
+
+    border_dash_offset: Option<f64>,
+
+    border_radius: Option<f64>,
+
+    padding: Option<Padding>,
+
+    shadow_color: Option<Color>,
+
+    shadow_blur: Option<f64>,
+
+    shadow_offset_x: Option<f64>,
+
+    shadow_offset_y: Option<f64>,
+
+    width: Option<CompositeValue>,
+
+    height: Option<f64>,
+
+    text_border_color: Option<Color>,
+
+    text_border_width: Option<f64>,
+
+    text_border_type: Option<BorderType>,
+
+    text_border_dash_offset: Option<f64>,
+
+    text_shadow_color: Option<Color>,
+
+    text_shadow_blur: Option<f64>,
+
+    text_shadow_offset_x: Option<f64>,
+
+    text_shadow_offset_y: Option<f64>,
+
+    overflow: Option<TextOverflow>,
+
+    ellipsis: Option<String>,
+
+    rich: Option<BTreeMap<String, TextStyle>>,
 }
 
 impl TextStyle {
@@ -32,6 +127,31 @@ impl TextStyle {
             font_size: None,
             line_height: None,
             align: None,
+            vertical_align: None,
+            background_color: None,
+            border_color: None,
+            border_width: None,
+            border_type: None,
+            border_dash_offset: None,
+            border_radius: None,
+            padding: None,
+            shadow_color: None,
+            shadow_blur: None,
+            shadow_offset_x: None,
+            shadow_offset_y: None,
+            width: None,
+            height: None,
+            text_border_color: None,
+            text_border_width: None,
+            text_border_type: None,
+            text_border_dash_offset: None,
+            text_shadow_color: None,
+            text_shadow_blur: None,
+            text_shadow_offset_x: None,
+            text_shadow_offset_y: None,
+            overflow: None,
+            ellipsis: None,
+            rich: None,
         }
     }
 
@@ -69,4 +189,176 @@ impl TextStyle {
         self.align = Some(align.into());
         self
     }
+
+    pub fn vertical_align<S: Into<String>>(mut self, vertical_align: S) -> Self {
+        self.vertical_align = Some(vertical_align.into());
+        self
+    }
+
+    pub fn background_color<B: Into<TextBackground>>(mut self, background_color: B) -> Self {
+        self.background_color = Some(background_color.into());
+        self
+    }
+
+    /// Use an image as the background of the text box. Combined with a
+    /// fixed `width` or `height`, this renders an inline icon.
+    pub fn background_image<S: Into<String>>(mut self, image: S) -> Self {
+        self.background_color = Some(TextBackground::Image {
+            image: image.into(),
+        });
+        self
+    }
+
+    pub fn border_color<C: Into<Color>>(mut self, border_color: C) -> Self {
+        self.border_color = Some(border_color.into());
+        self
+    }
+
+    pub fn border_width<F: Into<f64>>(mut self, border_width: F) -> Self {
+        self.border_width = Some(border_width.into());
+        self
+    }
+
+    pub fn border_type<B: Into<BorderType>>(mut self, border_type: B) -> Self {
+        self.border_type = Some(border_type.into());
+        self
+    }
+
+    pub fn border_dash_offset<F: Into<f64>>(mut self, border_dash_offset: F) -> Self {
+        self.border_dash_offset = Some(border_dash_offset.into());
+        self
+    }
+
+    pub fn border_radius<F: Into<f64>>(mut self, border_radius: F) -> Self {
+        self.border_radius = Some(border_radius.into());
+        self
+    }
+
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = Some(padding.into());
+        self
+    }
+
+    pub fn shadow_color<C: Into<Color>>(mut self, shadow_color: C) -> Self {
+        self.shadow_color = Some(shadow_color.into());
+        self
+    }
+
+    pub fn shadow_blur<F: Into<f64>>(mut self, shadow_blur: F) -> Self {
+        self.shadow_blur = Some(shadow_blur.into());
+        self
+    }
+
+    pub fn shadow_offset_x<F: Into<f64>>(mut self, shadow_offset_x: F) -> Self {
+        self.shadow_offset_x = Some(shadow_offset_x.into());
+        self
+    }
+
+    pub fn shadow_offset_y<F: Into<f64>>(mut self, shadow_offset_y: F) -> Self {
+        self.shadow_offset_y = Some(shadow_offset_y.into());
+        self
+    }
+
+    /// Width of the text box, either in pixels or as a percentage of the
+    /// enclosing box. Required for `overflow` to take effect.
+    pub fn width<C: Into<CompositeValue>>(mut self, width: C) -> Self {
+        self.width = Some(width.into());
+        self
+    }
+
+    pub fn height<F: Into<f64>>(mut self, height: F) -> Self {
+        self.height = Some(height.into());
+        self
+    }
+
+    pub fn text_border_color<C: Into<Color>>(mut self, text_border_color: C) -> Self {
+        self.text_border_color = Some(text_border_color.into());
+        self
+    }
+
+    pub fn text_border_width<F: Into<f64>>(mut self, text_border_width: F) -> Self {
+        self.text_border_width = Some(text_border_width.into());
+        self
+    }
+
+    pub fn text_border_type<B: Into<BorderType>>(mut self, text_border_type: B) -> Self {
+        self.text_border_type = Some(text_border_type.into());
+        self
+    }
+
+    pub fn text_border_dash_offset<F: Into<f64>>(mut self, text_border_dash_offset: F) -> Self {
+        self.text_border_dash_offset = Some(text_border_dash_offset.into());
+        self
+    }
+
+    pub fn text_shadow_color<C: Into<Color>>(mut self, text_shadow_color: C) -> Self {
+        self.text_shadow_color = Some(text_shadow_color.into());
+        self
+    }
+
+    pub fn text_shadow_blur<F: Into<f64>>(mut self, text_shadow_blur: F) -> Self {
+        self.text_shadow_blur = Some(text_shadow_blur.into());
+        self
+    }
+
+    pub fn text_shadow_offset_x<F: Into<f64>>(mut self, text_shadow_offset_x: F) -> Self {
+        self.text_shadow_offset_x = Some(text_shadow_offset_x.into());
+        self
+    }
+
+    pub fn text_shadow_offset_y<F: Into<f64>>(mut self, text_shadow_offset_y: F) -> Self {
+        self.text_shadow_offset_y = Some(text_shadow_offset_y.into());
+        self
+    }
+
+    pub fn overflow<O: Into<TextOverflow>>(mut self, overflow: O) -> Self {
+        self.overflow = Some(overflow.into());
+        self
+    }
+
+    /// String appended to truncated text, `"..."` by default.
+    pub fn ellipsis<S: Into<String>>(mut self, ellipsis: S) -> Self {
+        self.ellipsis = Some(ellipsis.into());
+        self
+    }
+
+    /// Add a named rich text style, referenced from a formatter as
+    /// `{name|text}`.
+    pub fn rich<S: Into<String>, T: Into<TextStyle>>(mut self, name: S, style: T) -> Self {
+        self.rich
+            .get_or_insert_with(BTreeMap::new)
+            .insert(name.into(), style.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rich_text_style() {
+        let text_style = TextStyle::new()
+            .font_size(14)
+            .rich("value", TextStyle::new().font_weight("bold").font_size(20))
+            .rich("unit", TextStyle::new().color("#999"))
+            .rich(
+                "icon",
+                TextStyle::new().height(16).background_image("sun.png"),
+            );
+        assert_eq!(
+            serde_json::to_value(&text_style).unwrap(),
+            serde_json::json!({
+                "fontSize": 14.0,
+                "rich": {
+                    "icon": {
+                        "backgroundColor": { "image": "sun.png" },
+                        "height": 16.0,
+                    },
+                    "unit": { "color": "#999" },
+                    "value": { "fontWeight": "bold", "fontSize": 20.0 },
+                },
+            })
+        );
+    }
 }
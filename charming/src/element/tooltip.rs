@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
 use macros::serde_auto;
 
-use crate::element::{AxisPointer, Color, Formatter, Padding};
+use crate::{
+    datatype::CompositeValue,
+    element::{AxisPointer, Color, Formatter, Padding, RawString, TextStyle},
+};
 
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -24,19 +27,110 @@ pub enum Trigger {
     None,
 }
 
+/// Position of the tooltip's floating layer.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TooltipPosition {
+    /// Absolute position relative to the container, in pixels or
+    /// percentages.
+    Point(CompositeValue, CompositeValue),
+
+    /// A keyword relative to the graphic element: `inside`, `top`, `left`,
+    /// `right` or `bottom`.
+    Named(String),
+
+    /// A JavaScript function returning the position.
+    Function(RawString),
+}
+
+impl From<&str> for TooltipPosition {
+    fn from(position: &str) -> Self {
+        TooltipPosition::Named(position.to_string())
+    }
+}
+
+impl From<String> for TooltipPosition {
+    fn from(position: String) -> Self {
+        TooltipPosition::Named(position)
+    }
+}
+
+impl From<RawString> for TooltipPosition {
+    fn from(position: RawString) -> Self {
+        TooltipPosition::Function(position)
+    }
+}
+
+impl<X: Into<CompositeValue>, Y: Into<CompositeValue>> From<(X, Y)> for TooltipPosition {
+    fn from((x, y): (X, Y)) -> Self {
+        TooltipPosition::Point(x.into(), y.into())
+    }
+}
+
+/// Order of the series in an axis-triggered tooltip.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TooltipOrder {
+    SeriesAsc,
+    SeriesDesc,
+    ValueAsc,
+    ValueDesc,
+}
+
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TooltipRenderMode {
+    /// Render the tooltip as an HTML element.
+    Html,
+
+    /// Render the tooltip on the canvas or SVG, e.g. for server side
+    /// rendering where there is no DOM.
+    RichText,
+}
+
+/// Tooltip component. The same options are accepted by the chart, by
+/// coordinate systems and by each series, with the more specific tooltip
+/// taking precedence.
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Tooltip {
+    show: Option<bool>,
+
     trigger: Option<Trigger>,
 
     trigger_on: Option<TriggerOn>,
 
     axis_pointer: Option<AxisPointer>,
 
+    show_content: Option<bool>,
+
+    always_show_content: Option<bool>,
+
+    show_delay: Option<f64>,
+
+    hide_delay: Option<f64>,
+
+    enterable: Option<bool>,
+
+    render_mode: Option<TooltipRenderMode>,
+
+    confine: Option<bool>,
+
+    append_to_body: Option<bool>,
+
+    class_name: Option<String>,
+
+    transition_duration: Option<f64>,
+
     formatter: Option<Formatter>,
 
-    position: Option<String>,
+    value_formatter: Option<RawString>,
+
+    position: Option<TooltipPosition>,
 
     padding: Option<Padding>,
 
@@ -45,23 +139,49 @@ pub struct Tooltip {
     border_color: Option<Color>,
 
     border_width: Option<f64>,
+
+    text_style: Option<TextStyle>,
+
+    extra_css_text: Option<String>,
+
+    order: Option<TooltipOrder>,
 }
 
 impl Tooltip {
     pub fn new() -> Self {
         Self {
+            show: None,
             trigger: None,
             trigger_on: None,
             axis_pointer: None,
+            show_content: None,
+            always_show_content: None,
+            show_delay: None,
+            hide_delay: None,
+            enterable: None,
+            render_mode: None,
+            confine: None,
+            append_to_body: None,
+            class_name: None,
+            transition_duration: None,
             formatter: None,
+            value_formatter: None,
             position: None,
             padding: None,
             background_color: None,
             border_color: None,
             border_width: None,
+            text_style: None,
+            extra_css_text: None,
+            order: None,
         }
     }
 
+    pub fn show(mut self, show: bool) -> Self {
+        self.show = Some(show);
+        self
+    }
+
     pub fn trigger<T: Into<Trigger>>(mut self, trigger: T) -> Self {
         self.trigger = Some(trigger.into());
         self
@@ -77,12 +197,76 @@ impl Tooltip {
         self
     }
 
+    /// Whether to show the floating layer. Set to `false` to only trigger
+    /// the axis pointer.
+    pub fn show_content(mut self, show_content: bool) -> Self {
+        self.show_content = Some(show_content);
+        self
+    }
+
+    pub fn always_show_content(mut self, always_show_content: bool) -> Self {
+        self.always_show_content = Some(always_show_content);
+        self
+    }
+
+    /// Delay in milliseconds before the floating layer is shown.
+    pub fn show_delay<F: Into<f64>>(mut self, show_delay: F) -> Self {
+        self.show_delay = Some(show_delay.into());
+        self
+    }
+
+    /// Delay in milliseconds before the floating layer is hidden.
+    pub fn hide_delay<F: Into<f64>>(mut self, hide_delay: F) -> Self {
+        self.hide_delay = Some(hide_delay.into());
+        self
+    }
+
+    /// Whether the mouse can enter the floating layer, e.g. to click links
+    /// inside it.
+    pub fn enterable(mut self, enterable: bool) -> Self {
+        self.enterable = Some(enterable);
+        self
+    }
+
+    pub fn render_mode<R: Into<TooltipRenderMode>>(mut self, render_mode: R) -> Self {
+        self.render_mode = Some(render_mode.into());
+        self
+    }
+
+    /// Whether to keep the floating layer inside the chart container.
+    pub fn confine(mut self, confine: bool) -> Self {
+        self.confine = Some(confine);
+        self
+    }
+
+    pub fn append_to_body(mut self, append_to_body: bool) -> Self {
+        self.append_to_body = Some(append_to_body);
+        self
+    }
+
+    pub fn class_name<S: Into<String>>(mut self, class_name: S) -> Self {
+        self.class_name = Some(class_name.into());
+        self
+    }
+
+    pub fn transition_duration<F: Into<f64>>(mut self, transition_duration: F) -> Self {
+        self.transition_duration = Some(transition_duration.into());
+        self
+    }
+
     pub fn formatter<F: Into<Formatter>>(mut self, formatter: F) -> Self {
         self.formatter = Some(formatter.into());
         self
     }
 
-    pub fn position<S: Into<String>>(mut self, position: S) -> Self {
+    /// JavaScript function applied to the value part of the default tooltip
+    /// content, e.g. `"function (value) { return value + ' ms'; }"`.
+    pub fn value_formatter<R: Into<RawString>>(mut self, value_formatter: R) -> Self {
+        self.value_formatter = Some(value_formatter.into());
+        self
+    }
+
+    pub fn position<P: Into<TooltipPosition>>(mut self, position: P) -> Self {
         self.position = Some(position.into());
         self
     }
@@ -106,4 +290,56 @@ impl Tooltip {
         self.border_width = Some(border_width.into());
         self
     }
+
+    pub fn text_style<T: Into<TextStyle>>(mut self, text_style: T) -> Self {
+        self.text_style = Some(text_style.into());
+        self
+    }
+
+    /// Extra CSS appended to the floating layer's style, e.g. a box shadow.
+    pub fn extra_css_text<S: Into<String>>(mut self, extra_css_text: S) -> Self {
+        self.extra_css_text = Some(extra_css_text.into());
+        self
+    }
+
+    pub fn order<O: Into<TooltipOrder>>(mut self, order: O) -> Self {
+        self.order = Some(order.into());
+        self
+    }
+}
+
+/// Tooltip of a coordinate system.
+#[deprecated(note = "use `Tooltip` instead")]
+pub type CoordinateTooltip = Tooltip;
+
+#[cfg(test)]
+mod test {
+    use crate::element::process_raw_strings;
+
+    use super::*;
+
+    #[test]
+    fn tooltip_value_formatter() {
+        let tooltip = Tooltip::new()
+            .trigger(Trigger::Axis)
+            .confine(true)
+            .position(("10%", 20))
+            .value_formatter(RawString::from(
+                "function (value) { return value + ' ms'; }",
+            ))
+            .order(TooltipOrder::ValueDesc);
+        assert_eq!(
+            process_raw_strings(&serde_json::to_string(&tooltip).unwrap()),
+            r#"{"trigger":"axis","confine":true,"valueFormatter":function (value) { return value + ' ms'; },"position":["10%",20],"order":"valueDesc"}"#
+        );
+    }
+
+    #[test]
+    fn tooltip_value_formatter_from_str() {
+        let tooltip = Tooltip::new().value_formatter("function (value) { return value + ' ms'; }");
+        assert_eq!(
+            process_raw_strings(&serde_json::to_string(&tooltip).unwrap()),
+            r#"{"valueFormatter":function (value) { return value + ' ms'; }}"#
+        );
+    }
 }
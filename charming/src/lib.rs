@@ -100,7 +100,10 @@ use component::{
     Title, Toolbox, VisualMap,
 };
 use datatype::Dataset;
-use element::{process_raw_strings, AxisPointer, Color, MarkLine, Tooltip};
+use element::{
+    impl_animation, process_raw_strings, Animation, AxisPointer, BlendMode, Color, MarkLine, Media,
+    StateAnimation, TextStyle, Tooltip,
+};
 use serde::{Deserialize, Serialize};
 use macros::serde_auto;
 
@@ -296,10 +299,28 @@ pub struct Chart {
 
     background_color: Option<Color>,
 
+    text_style: Option<TextStyle>,
+
+    dark_mode: Option<bool>,
+
+    #[serde(flatten)]
+    animation: Animation,
+
+    state_animation: Option<StateAnimation>,
+
+    blend_mode: Option<BlendMode>,
+
+    hover_layer_threshold: Option<f64>,
+
+    #[serde(rename = "useUTC")]
+    use_utc: Option<bool>,
+
     mark_line: Option<MarkLine>,
 
     aria: Option<Aria>,
 
+    media: Vec<Media>,
+
     series: Vec<Series>,
 
     #[serde(skip_serializing)]
@@ -333,8 +354,16 @@ impl Chart {
             radar: vec![],
             color: vec![],
             background_color: None,
+            text_style: None,
+            dark_mode: None,
+            animation: Animation::new(),
+            state_animation: None,
+            blend_mode: None,
+            hover_layer_threshold: None,
+            use_utc: None,
             mark_line: None,
             aria: None,
+            media: vec![],
             series: vec![],
             geo_maps: vec![],
         }
@@ -469,6 +498,43 @@ impl Chart {
         self
     }
 
+    /// Global font style, inherited by all text in the chart.
+    pub fn text_style<T: Into<TextStyle>>(mut self, text_style: T) -> Self {
+        self.text_style = Some(text_style.into());
+        self
+    }
+
+    /// Whether to adjust the default colors for a dark background. Detected
+    /// from `background_color` when not set.
+    pub fn dark_mode(mut self, dark_mode: bool) -> Self {
+        self.dark_mode = Some(dark_mode);
+        self
+    }
+
+    /// Animation of state transitions, e.g. when an element is hovered.
+    pub fn state_animation<S: Into<StateAnimation>>(mut self, state_animation: S) -> Self {
+        self.state_animation = Some(state_animation.into());
+        self
+    }
+
+    pub fn blend_mode<B: Into<BlendMode>>(mut self, blend_mode: B) -> Self {
+        self.blend_mode = Some(blend_mode.into());
+        self
+    }
+
+    /// Number of graphic elements above which hover highlighting is drawn on
+    /// a separate layer.
+    pub fn hover_layer_threshold<F: Into<f64>>(mut self, hover_layer_threshold: F) -> Self {
+        self.hover_layer_threshold = Some(hover_layer_threshold.into());
+        self
+    }
+
+    /// Whether to display dates in UTC instead of the local time zone.
+    pub fn use_utc(mut self, use_utc: bool) -> Self {
+        self.use_utc = Some(use_utc);
+        self
+    }
+
     pub fn mark_line(mut self, mark_line: MarkLine) -> Self {
         self.mark_line = Some(mark_line);
         self
@@ -479,6 +545,13 @@ impl Chart {
         self
     }
 
+    /// Add a responsive override, applied while its query matches the size
+    /// of the chart container.
+    pub fn media(mut self, media: Media) -> Self {
+        self.media.push(media);
+        self
+    }
+
     pub fn series<S: Into<Series>>(mut self, series: S) -> Self {
         self.series.push(series.into());
         self
@@ -496,6 +569,8 @@ impl Chart {
     }
 }
 
+impl_animation!(Chart);
+
 impl ToString for Chart {
     fn to_string(&self) -> String {
         process_raw_strings(serde_json::to_string_pretty(self).unwrap().as_str())
@@ -504,6 +579,7 @@ impl ToString for Chart {
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum EchartsError {
+    DatasetError(String),
     HtmlRenderingError(String),
     ImageRenderingError(String),
     JsRuntimeError(String),
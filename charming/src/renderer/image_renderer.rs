@@ -19,8 +19,7 @@ var chart = echarts.init(null, {{#if theme}}'{{ theme }}'{{else}}null{{/if}}, {
     height: {{ height }}
 });
 
-chart.setOption({ animation: false });
-chart.setOption({{{ chart_option }}});
+chart.setOption(Object.assign({{{ chart_option }}}, { animation: false }));
 chart.renderToSVGString();
 "#;
 
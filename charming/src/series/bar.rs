@@ -1,25 +1,27 @@
 use std::vec;
 
-use serde::{Deserialize, Serialize};
 use macros::serde_auto;
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    datatype::{DataFrame, DataPoint},
-    element::{BackgroundStyle, ColorBy, CoordinateSystem, Emphasis, ItemStyle, Label, MarkLine},
+    datatype::{CompositeValue, DataFrame, DataPoint},
+    element::{
+        impl_animation, Animation, BackgroundStyle, Blur, ColorBy, CoordinateSystem,
+        DimensionEncode, Emphasis, ItemStyle, Label, MarkArea, MarkLine, MarkPoint, Sampling,
+        Select, SelectedMode, SeriesLayoutBy, Tooltip,
+    },
 };
 
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Bar {
-    // #[serde(rename = "type")]
-    // type_: String,
     id: Option<String>,
 
-    color_by: Option<ColorBy>,
-
     name: Option<String>,
 
+    color_by: Option<ColorBy>,
+
     legend_hover_link: Option<bool>,
 
     coordinate_system: Option<CoordinateSystem>,
@@ -42,13 +44,64 @@ pub struct Bar {
 
     item_style: Option<ItemStyle>,
 
-    emphais: Option<Emphasis>,
+    emphasis: Option<Emphasis>,
 
-    mark_line: Option<MarkLine>,
+    blur: Option<Blur>,
+
+    select: Option<Select>,
+
+    selected_mode: Option<SelectedMode>,
 
     stack: Option<String>,
 
-    bar_width: Option<f64>,
+    sampling: Option<Sampling>,
+
+    bar_width: Option<CompositeValue>,
+
+    bar_max_width: Option<CompositeValue>,
+
+    bar_min_width: Option<CompositeValue>,
+
+    bar_min_height: Option<f64>,
+
+    bar_min_angle: Option<f64>,
+
+    bar_gap: Option<String>,
+
+    bar_category_gap: Option<String>,
+
+    large: Option<bool>,
+
+    large_threshold: Option<f64>,
+
+    progressive: Option<f64>,
+
+    progressive_threshold: Option<f64>,
+
+    dataset_index: Option<f64>,
+
+    dataset_id: Option<String>,
+
+    series_layout_by: Option<SeriesLayoutBy>,
+
+    encode: Option<DimensionEncode>,
+
+    mark_point: Option<MarkPoint>,
+
+    mark_line: Option<MarkLine>,
+
+    mark_area: Option<MarkArea>,
+
+    clip: Option<bool>,
+
+    #[serde(flatten)]
+    animation: Animation,
+
+    tooltip: Option<Tooltip>,
+
+    zlevel: Option<f64>,
+
+    z: Option<f64>,
 
     data: DataFrame,
 }
@@ -56,7 +109,6 @@ pub struct Bar {
 impl Bar {
     pub fn new() -> Self {
         Self {
-            //             // type_: "bar".to_string(),
             id: None,
             name: None,
             color_by: None,
@@ -71,10 +123,35 @@ impl Bar {
             background_style: None,
             label: None,
             item_style: None,
-            emphais: None,
-            mark_line: None,
+            emphasis: None,
+            blur: None,
+            select: None,
+            selected_mode: None,
             stack: None,
+            sampling: None,
             bar_width: None,
+            bar_max_width: None,
+            bar_min_width: None,
+            bar_min_height: None,
+            bar_min_angle: None,
+            bar_gap: None,
+            bar_category_gap: None,
+            large: None,
+            large_threshold: None,
+            progressive: None,
+            progressive_threshold: None,
+            dataset_index: None,
+            dataset_id: None,
+            series_layout_by: None,
+            encode: None,
+            mark_point: None,
+            mark_line: None,
+            mark_area: None,
+            clip: None,
+            animation: Animation::new(),
+            tooltip: None,
+            zlevel: None,
+            z: None,
             data: vec![],
         }
     }
@@ -99,8 +176,8 @@ impl Bar {
         self
     }
 
-    pub fn coordinate_system<C: Into<CoordinateSystem>>(mut self, coordiate_system: C) -> Self {
-        self.coordinate_system = Some(coordiate_system.into());
+    pub fn coordinate_system<C: Into<CoordinateSystem>>(mut self, coordinate_system: C) -> Self {
+        self.coordinate_system = Some(coordinate_system.into());
         self
     }
 
@@ -150,12 +227,22 @@ impl Bar {
     }
 
     pub fn emphasis<E: Into<Emphasis>>(mut self, emphasis: E) -> Self {
-        self.emphais = Some(emphasis.into());
+        self.emphasis = Some(emphasis.into());
         self
     }
 
-    pub fn mark_line<M: Into<MarkLine>>(mut self, mark_line: M) -> Self {
-        self.mark_line = Some(mark_line.into());
+    pub fn blur<B: Into<Blur>>(mut self, blur: B) -> Self {
+        self.blur = Some(blur.into());
+        self
+    }
+
+    pub fn select<S: Into<Select>>(mut self, select: S) -> Self {
+        self.select = Some(select.into());
+        self
+    }
+
+    pub fn selected_mode<S: Into<SelectedMode>>(mut self, selected_mode: S) -> Self {
+        self.selected_mode = Some(selected_mode.into());
         self
     }
 
@@ -164,13 +251,169 @@ impl Bar {
         self
     }
 
-    pub fn bar_width<F: Into<f64>>(mut self, bar_width: F) -> Self {
+    /// The down-sampling strategy used when the amount of data is much larger
+    /// than the number of pixels.
+    pub fn sampling<S: Into<Sampling>>(mut self, sampling: S) -> Self {
+        self.sampling = Some(sampling.into());
+        self
+    }
+
+    /// Width of the bars, either in pixels or as a percentage of the category
+    /// width, e.g. `"60%"`.
+    pub fn bar_width<C: Into<CompositeValue>>(mut self, bar_width: C) -> Self {
         self.bar_width = Some(bar_width.into());
         self
     }
 
+    pub fn bar_max_width<C: Into<CompositeValue>>(mut self, bar_max_width: C) -> Self {
+        self.bar_max_width = Some(bar_max_width.into());
+        self
+    }
+
+    pub fn bar_min_width<C: Into<CompositeValue>>(mut self, bar_min_width: C) -> Self {
+        self.bar_min_width = Some(bar_min_width.into());
+        self
+    }
+
+    /// Minimum height of the bars in pixels, to keep tiny values interactive.
+    pub fn bar_min_height<F: Into<f64>>(mut self, bar_min_height: F) -> Self {
+        self.bar_min_height = Some(bar_min_height.into());
+        self
+    }
+
+    /// Minimum angle of the bars in a polar coordinate system.
+    pub fn bar_min_angle<F: Into<f64>>(mut self, bar_min_angle: F) -> Self {
+        self.bar_min_angle = Some(bar_min_angle.into());
+        self
+    }
+
+    /// Gap between bars of different series in the same category, as a
+    /// percentage of the bar width, e.g. `"30%"`. Use `"-100%"` to overlap
+    /// the bars.
+    pub fn bar_gap<S: Into<String>>(mut self, bar_gap: S) -> Self {
+        self.bar_gap = Some(bar_gap.into());
+        self
+    }
+
+    /// Gap between categories, as a percentage of the category width, e.g.
+    /// `"20%"`.
+    pub fn bar_category_gap<S: Into<String>>(mut self, bar_category_gap: S) -> Self {
+        self.bar_category_gap = Some(bar_category_gap.into());
+        self
+    }
+
+    /// Whether to enable the optimization of large-scale data.
+    pub fn large(mut self, large: bool) -> Self {
+        self.large = Some(large);
+        self
+    }
+
+    /// The number of data items above which the large mode is used.
+    pub fn large_threshold<F: Into<f64>>(mut self, large_threshold: F) -> Self {
+        self.large_threshold = Some(large_threshold.into());
+        self
+    }
+
+    pub fn progressive<F: Into<f64>>(mut self, progressive: F) -> Self {
+        self.progressive = Some(progressive.into());
+        self
+    }
+
+    pub fn progressive_threshold<F: Into<f64>>(mut self, progressive_threshold: F) -> Self {
+        self.progressive_threshold = Some(progressive_threshold.into());
+        self
+    }
+
+    pub fn dataset_index<F: Into<f64>>(mut self, dataset_index: F) -> Self {
+        self.dataset_index = Some(dataset_index.into());
+        self
+    }
+
+    pub fn dataset_id<S: Into<String>>(mut self, dataset_id: S) -> Self {
+        self.dataset_id = Some(dataset_id.into());
+        self
+    }
+
+    pub fn series_layout_by<S: Into<SeriesLayoutBy>>(mut self, series_layout_by: S) -> Self {
+        self.series_layout_by = Some(series_layout_by.into());
+        self
+    }
+
+    pub fn encode<D: Into<DimensionEncode>>(mut self, encode: D) -> Self {
+        self.encode = Some(encode.into());
+        self
+    }
+
+    pub fn mark_point<M: Into<MarkPoint>>(mut self, mark_point: M) -> Self {
+        self.mark_point = Some(mark_point.into());
+        self
+    }
+
+    pub fn mark_line<M: Into<MarkLine>>(mut self, mark_line: M) -> Self {
+        self.mark_line = Some(mark_line.into());
+        self
+    }
+
+    pub fn mark_area<M: Into<MarkArea>>(mut self, mark_area: M) -> Self {
+        self.mark_area = Some(mark_area.into());
+        self
+    }
+
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = Some(clip);
+        self
+    }
+
+    pub fn tooltip<T: Into<Tooltip>>(mut self, tooltip: T) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    pub fn zlevel<F: Into<f64>>(mut self, zlevel: F) -> Self {
+        self.zlevel = Some(zlevel.into());
+        self
+    }
+
+    pub fn z<F: Into<f64>>(mut self, z: F) -> Self {
+        self.z = Some(z.into());
+        self
+    }
+
     pub fn data<D: Into<DataPoint>>(mut self, data: Vec<D>) -> Self {
         self.data = data.into_iter().map(|d| d.into()).collect();
         self
     }
 }
+
+impl_animation!(Bar);
+
+#[cfg(test)]
+mod test {
+    use crate::element::EmphasisFocus;
+
+    use super::*;
+
+    #[test]
+    fn bar_options() {
+        let bar = Bar::new()
+            .emphasis(Emphasis::new().focus(EmphasisFocus::Series))
+            .bar_width("60%")
+            .bar_gap("-100%")
+            .bar_category_gap("40%")
+            .large(true)
+            .sampling(Sampling::Lttb)
+            .series_layout_by(SeriesLayoutBy::Row);
+        assert_eq!(
+            serde_json::to_value(&bar).unwrap(),
+            serde_json::json!({
+                "emphasis": {"focus": "series"},
+                "sampling": "lttb",
+                "barWidth": "60%",
+                "barGap": "-100%",
+                "barCategoryGap": "40%",
+                "large": true,
+                "seriesLayoutBy": "row",
+            })
+        );
+    }
+}
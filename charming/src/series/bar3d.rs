@@ -3,7 +3,7 @@ use macros::serde_auto;
 
 use crate::{
     datatype::{CompositeValue, DataFrame, DataPoint},
-    element::{CoordinateSystem, DimensionEncode},
+    element::{CoordinateSystem, DimensionEncode, Tooltip},
 };
 
 #[serde_auto]
@@ -25,6 +25,8 @@ pub struct Bar3d {
 
     encode: Option<DimensionEncode>,
 
+    tooltip: Option<Tooltip>,
+
     data: DataFrame,
 }
 impl Bar3d {
@@ -38,6 +40,7 @@ impl Bar3d {
             globe_index: None,
             shading: None,
             encode: None,
+            tooltip: None,
             data: DataFrame::new(),
         }
     }
@@ -77,6 +80,11 @@ impl Bar3d {
         self
     }
 
+    pub fn tooltip<T: Into<Tooltip>>(mut self, tooltip: T) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
     pub fn data<D: Into<DataPoint>>(mut self, data: Vec<D>) -> Self {
         self.data = data.into_iter().map(|d| d.into()).collect();
         self
@@ -1,8 +1,17 @@
 use serde::{Deserialize, Serialize};
 use macros::serde_auto;
 
-use crate::element::{ColorBy, CoordinateSystem};
+use crate::{
+    datatype::{CompositeValue, DataFrame, DataPoint},
+    element::{
+        impl_animation, Animation, Blur, ColorBy, CoordinateSystem, DimensionEncode, Emphasis,
+        ItemStyle, MarkArea, MarkLine, MarkPoint, Orient, Select, SelectedMode, SeriesLayoutBy,
+        Tooltip,
+    },
+};
 
+/// Box-and-whisker plot. Each data item is `[min, Q1, median, Q3, max]`,
+/// which can be computed with the `boxplot` dataset transform.
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -13,13 +22,54 @@ pub struct Boxplot {
 
     coordinate_system: Option<CoordinateSystem>,
 
+    x_axis_index: Option<f64>,
+
+    y_axis_index: Option<f64>,
+
     color_by: Option<ColorBy>,
 
     legend_hover_link: Option<bool>,
 
     hover_animation: Option<bool>,
 
+    layout: Option<Orient>,
+
+    box_width: Option<(CompositeValue, CompositeValue)>,
+
+    item_style: Option<ItemStyle>,
+
+    emphasis: Option<Emphasis>,
+
+    blur: Option<Blur>,
+
+    select: Option<Select>,
+
+    selected_mode: Option<SelectedMode>,
+
     dataset_index: Option<u64>,
+
+    dataset_id: Option<String>,
+
+    series_layout_by: Option<SeriesLayoutBy>,
+
+    encode: Option<DimensionEncode>,
+
+    mark_point: Option<MarkPoint>,
+
+    mark_line: Option<MarkLine>,
+
+    mark_area: Option<MarkArea>,
+
+    #[serde(flatten)]
+    animation: Animation,
+
+    tooltip: Option<Tooltip>,
+
+    zlevel: Option<f64>,
+
+    z: Option<f64>,
+
+    data: DataFrame,
 }
 
 impl Boxplot {
@@ -28,10 +78,30 @@ impl Boxplot {
             id: None,
             name: None,
             coordinate_system: None,
+            x_axis_index: None,
+            y_axis_index: None,
             color_by: None,
             legend_hover_link: None,
             hover_animation: None,
+            layout: None,
+            box_width: None,
+            item_style: None,
+            emphasis: None,
+            blur: None,
+            select: None,
+            selected_mode: None,
             dataset_index: None,
+            dataset_id: None,
+            series_layout_by: None,
+            encode: None,
+            mark_point: None,
+            mark_line: None,
+            mark_area: None,
+            animation: Animation::new(),
+            tooltip: None,
+            zlevel: None,
+            z: None,
+            data: vec![],
         }
     }
 
@@ -50,6 +120,16 @@ impl Boxplot {
         self
     }
 
+    pub fn x_axis_index<F: Into<f64>>(mut self, x_axis_index: F) -> Self {
+        self.x_axis_index = Some(x_axis_index.into());
+        self
+    }
+
+    pub fn y_axis_index<F: Into<f64>>(mut self, y_axis_index: F) -> Self {
+        self.y_axis_index = Some(y_axis_index.into());
+        self
+    }
+
     pub fn color_by<C: Into<ColorBy>>(mut self, color_by: C) -> Self {
         self.color_by = Some(color_by.into());
         self
@@ -65,8 +145,125 @@ impl Boxplot {
         self
     }
 
+    /// Orientation of the boxes. Defaults to vertical boxes on a horizontal
+    /// category axis.
+    pub fn layout<O: Into<Orient>>(mut self, layout: O) -> Self {
+        self.layout = Some(layout.into());
+        self
+    }
+
+    /// Lower and upper bound of the box width, either in pixels or as a
+    /// percentage of the category width.
+    pub fn box_width<C: Into<CompositeValue>>(mut self, box_width: (C, C)) -> Self {
+        self.box_width = Some((box_width.0.into(), box_width.1.into()));
+        self
+    }
+
+    pub fn item_style<I: Into<ItemStyle>>(mut self, item_style: I) -> Self {
+        self.item_style = Some(item_style.into());
+        self
+    }
+
+    pub fn emphasis<E: Into<Emphasis>>(mut self, emphasis: E) -> Self {
+        self.emphasis = Some(emphasis.into());
+        self
+    }
+
+    pub fn blur<B: Into<Blur>>(mut self, blur: B) -> Self {
+        self.blur = Some(blur.into());
+        self
+    }
+
+    pub fn select<S: Into<Select>>(mut self, select: S) -> Self {
+        self.select = Some(select.into());
+        self
+    }
+
+    pub fn selected_mode<S: Into<SelectedMode>>(mut self, selected_mode: S) -> Self {
+        self.selected_mode = Some(selected_mode.into());
+        self
+    }
+
     pub fn dataset_index(mut self, dataset_index: u64) -> Self {
         self.dataset_index = Some(dataset_index);
         self
     }
+
+    pub fn dataset_id<S: Into<String>>(mut self, dataset_id: S) -> Self {
+        self.dataset_id = Some(dataset_id.into());
+        self
+    }
+
+    pub fn series_layout_by<S: Into<SeriesLayoutBy>>(mut self, series_layout_by: S) -> Self {
+        self.series_layout_by = Some(series_layout_by.into());
+        self
+    }
+
+    pub fn encode<E: Into<DimensionEncode>>(mut self, encode: E) -> Self {
+        self.encode = Some(encode.into());
+        self
+    }
+
+    pub fn mark_point<M: Into<MarkPoint>>(mut self, mark_point: M) -> Self {
+        self.mark_point = Some(mark_point.into());
+        self
+    }
+
+    pub fn mark_line<M: Into<MarkLine>>(mut self, mark_line: M) -> Self {
+        self.mark_line = Some(mark_line.into());
+        self
+    }
+
+    pub fn mark_area<M: Into<MarkArea>>(mut self, mark_area: M) -> Self {
+        self.mark_area = Some(mark_area.into());
+        self
+    }
+
+    pub fn tooltip<T: Into<Tooltip>>(mut self, tooltip: T) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    pub fn zlevel<F: Into<f64>>(mut self, zlevel: F) -> Self {
+        self.zlevel = Some(zlevel.into());
+        self
+    }
+
+    pub fn z<F: Into<f64>>(mut self, z: F) -> Self {
+        self.z = Some(z.into());
+        self
+    }
+
+    pub fn data<D: Into<DataPoint>>(mut self, data: Vec<D>) -> Self {
+        self.data = data.into_iter().map(|d| d.into()).collect();
+        self
+    }
+}
+
+impl_animation!(Boxplot);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn boxplot_options() {
+        let boxplot = Boxplot::new()
+            .name("latency")
+            .x_axis_index(1)
+            .box_width(("10%", "50%"))
+            .data(vec![
+                vec![850, 940, 980, 1070, 1175],
+                vec![890, 910, 950, 990, 1040],
+            ]);
+        assert_eq!(
+            serde_json::to_value(&boxplot).unwrap(),
+            serde_json::json!({
+                "name": "latency",
+                "xAxisIndex": 1.0,
+                "boxWidth": ["10%", "50%"],
+                "data": [[850, 940, 980, 1070, 1175], [890, 910, 950, 990, 1040]],
+            })
+        );
+    }
 }
@@ -2,10 +2,16 @@ use serde::{Deserialize, Serialize};
 use macros::serde_auto;
 
 use crate::{
-    datatype::{DataFrame, DataPoint},
-    element::{ColorBy, CoordinateSystem},
+    datatype::{CompositeValue, DataFrame, DataPoint},
+    element::{
+        impl_animation, Animation, Blur, ColorBy, CoordinateSystem, DimensionEncode, Emphasis,
+        ItemStyle, MarkArea, MarkLine, MarkPoint, Orient, Select, SelectedMode, SeriesLayoutBy,
+        Tooltip,
+    },
 };
 
+/// Candlestick (K-line) chart. Each data item is `[open, close, lowest,
+/// highest]`.
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -14,12 +20,68 @@ pub struct Candlestick {
 
     name: Option<String>,
 
-    coordiate_system: Option<CoordinateSystem>,
+    coordinate_system: Option<CoordinateSystem>,
+
+    x_axis_index: Option<f64>,
+
+    y_axis_index: Option<f64>,
 
     color_by: Option<ColorBy>,
 
     legend_hover_link: Option<bool>,
 
+    hover_animation: Option<bool>,
+
+    layout: Option<Orient>,
+
+    bar_width: Option<CompositeValue>,
+
+    bar_min_width: Option<CompositeValue>,
+
+    bar_max_width: Option<CompositeValue>,
+
+    item_style: Option<ItemStyle>,
+
+    emphasis: Option<Emphasis>,
+
+    blur: Option<Blur>,
+
+    select: Option<Select>,
+
+    selected_mode: Option<SelectedMode>,
+
+    large: Option<bool>,
+
+    large_threshold: Option<f64>,
+
+    progressive: Option<f64>,
+
+    progressive_threshold: Option<f64>,
+
+    dataset_index: Option<f64>,
+
+    dataset_id: Option<String>,
+
+    series_layout_by: Option<SeriesLayoutBy>,
+
+    encode: Option<DimensionEncode>,
+
+    mark_point: Option<MarkPoint>,
+
+    mark_line: Option<MarkLine>,
+
+    mark_area: Option<MarkArea>,
+
+    clip: Option<bool>,
+
+    #[serde(flatten)]
+    animation: Animation,
+
+    tooltip: Option<Tooltip>,
+
+    zlevel: Option<f64>,
+
+    z: Option<f64>,
 
     data: DataFrame,
 }
@@ -29,9 +91,37 @@ impl Candlestick {
         Self {
             id: None,
             name: None,
-            coordiate_system: None,
+            coordinate_system: None,
+            x_axis_index: None,
+            y_axis_index: None,
             color_by: None,
             legend_hover_link: None,
+            hover_animation: None,
+            layout: None,
+            bar_width: None,
+            bar_min_width: None,
+            bar_max_width: None,
+            item_style: None,
+            emphasis: None,
+            blur: None,
+            select: None,
+            selected_mode: None,
+            large: None,
+            large_threshold: None,
+            progressive: None,
+            progressive_threshold: None,
+            dataset_index: None,
+            dataset_id: None,
+            series_layout_by: None,
+            encode: None,
+            mark_point: None,
+            mark_line: None,
+            mark_area: None,
+            clip: None,
+            animation: Animation::new(),
+            tooltip: None,
+            zlevel: None,
+            z: None,
             data: vec![],
         }
     }
@@ -46,8 +136,23 @@ impl Candlestick {
         self
     }
 
-    pub fn coordiate_system<C: Into<CoordinateSystem>>(mut self, coordiate_system: C) -> Self {
-        self.coordiate_system = Some(coordiate_system.into());
+    pub fn coordinate_system<C: Into<CoordinateSystem>>(mut self, coordinate_system: C) -> Self {
+        self.coordinate_system = Some(coordinate_system.into());
+        self
+    }
+
+    #[deprecated(note = "use `coordinate_system` instead")]
+    pub fn coordiate_system<C: Into<CoordinateSystem>>(self, coordinate_system: C) -> Self {
+        self.coordinate_system(coordinate_system)
+    }
+
+    pub fn x_axis_index<F: Into<f64>>(mut self, x_axis_index: F) -> Self {
+        self.x_axis_index = Some(x_axis_index.into());
+        self
+    }
+
+    pub fn y_axis_index<F: Into<f64>>(mut self, y_axis_index: F) -> Self {
+        self.y_axis_index = Some(y_axis_index.into());
         self
     }
 
@@ -61,8 +166,181 @@ impl Candlestick {
         self
     }
 
+    pub fn hover_animation(mut self, hover_animation: bool) -> Self {
+        self.hover_animation = Some(hover_animation);
+        self
+    }
+
+    /// Orientation of the candlesticks. Defaults to vertical candlesticks on
+    /// a horizontal category axis.
+    pub fn layout<O: Into<Orient>>(mut self, layout: O) -> Self {
+        self.layout = Some(layout.into());
+        self
+    }
+
+    /// Width of the candlesticks, either in pixels or as a percentage of the
+    /// category width.
+    pub fn bar_width<C: Into<CompositeValue>>(mut self, bar_width: C) -> Self {
+        self.bar_width = Some(bar_width.into());
+        self
+    }
+
+    pub fn bar_min_width<C: Into<CompositeValue>>(mut self, bar_min_width: C) -> Self {
+        self.bar_min_width = Some(bar_min_width.into());
+        self
+    }
+
+    pub fn bar_max_width<C: Into<CompositeValue>>(mut self, bar_max_width: C) -> Self {
+        self.bar_max_width = Some(bar_max_width.into());
+        self
+    }
+
+    /// Style of the candlesticks. Use [`ItemStyle::color`] and
+    /// [`ItemStyle::color0`] for rising and falling colors respectively.
+    pub fn item_style<I: Into<ItemStyle>>(mut self, item_style: I) -> Self {
+        self.item_style = Some(item_style.into());
+        self
+    }
+
+    pub fn emphasis<E: Into<Emphasis>>(mut self, emphasis: E) -> Self {
+        self.emphasis = Some(emphasis.into());
+        self
+    }
+
+    pub fn blur<B: Into<Blur>>(mut self, blur: B) -> Self {
+        self.blur = Some(blur.into());
+        self
+    }
+
+    pub fn select<S: Into<Select>>(mut self, select: S) -> Self {
+        self.select = Some(select.into());
+        self
+    }
+
+    pub fn selected_mode<S: Into<SelectedMode>>(mut self, selected_mode: S) -> Self {
+        self.selected_mode = Some(selected_mode.into());
+        self
+    }
+
+    /// Whether to enable the optimization of large-scale data.
+    pub fn large(mut self, large: bool) -> Self {
+        self.large = Some(large);
+        self
+    }
+
+    /// The number of data items above which the large mode is used.
+    pub fn large_threshold<F: Into<f64>>(mut self, large_threshold: F) -> Self {
+        self.large_threshold = Some(large_threshold.into());
+        self
+    }
+
+    pub fn progressive<F: Into<f64>>(mut self, progressive: F) -> Self {
+        self.progressive = Some(progressive.into());
+        self
+    }
+
+    pub fn progressive_threshold<F: Into<f64>>(mut self, progressive_threshold: F) -> Self {
+        self.progressive_threshold = Some(progressive_threshold.into());
+        self
+    }
+
+    pub fn dataset_index<F: Into<f64>>(mut self, dataset_index: F) -> Self {
+        self.dataset_index = Some(dataset_index.into());
+        self
+    }
+
+    pub fn dataset_id<S: Into<String>>(mut self, dataset_id: S) -> Self {
+        self.dataset_id = Some(dataset_id.into());
+        self
+    }
+
+    pub fn series_layout_by<S: Into<SeriesLayoutBy>>(mut self, series_layout_by: S) -> Self {
+        self.series_layout_by = Some(series_layout_by.into());
+        self
+    }
+
+    pub fn encode<E: Into<DimensionEncode>>(mut self, encode: E) -> Self {
+        self.encode = Some(encode.into());
+        self
+    }
+
+    pub fn mark_point<M: Into<MarkPoint>>(mut self, mark_point: M) -> Self {
+        self.mark_point = Some(mark_point.into());
+        self
+    }
+
+    pub fn mark_line<M: Into<MarkLine>>(mut self, mark_line: M) -> Self {
+        self.mark_line = Some(mark_line.into());
+        self
+    }
+
+    pub fn mark_area<M: Into<MarkArea>>(mut self, mark_area: M) -> Self {
+        self.mark_area = Some(mark_area.into());
+        self
+    }
+
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = Some(clip);
+        self
+    }
+
+    pub fn tooltip<T: Into<Tooltip>>(mut self, tooltip: T) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    pub fn zlevel<F: Into<f64>>(mut self, zlevel: F) -> Self {
+        self.zlevel = Some(zlevel.into());
+        self
+    }
+
+    pub fn z<F: Into<f64>>(mut self, z: F) -> Self {
+        self.z = Some(z.into());
+        self
+    }
+
     pub fn data<D: Into<DataPoint>>(mut self, data: Vec<D>) -> Self {
         self.data = data.into_iter().map(|d| d.into()).collect();
         self
     }
 }
+
+impl_animation!(Candlestick);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn candlestick_options() {
+        let candlestick = Candlestick::new()
+            .coordinate_system(CoordinateSystem::Cartesian2d)
+            .y_axis_index(1)
+            .bar_width("60%")
+            .item_style(
+                ItemStyle::new()
+                    .color("#ec0000")
+                    .color0("#00da3c")
+                    .border_color("#8a0000")
+                    .border_color0("#008f28"),
+            )
+            .large(true)
+            .data(vec![vec![20, 34, 10, 38]]);
+        assert_eq!(
+            serde_json::to_value(&candlestick).unwrap(),
+            serde_json::json!({
+                "coordinateSystem": "cartesian2d",
+                "yAxisIndex": 1.0,
+                "barWidth": "60%",
+                "itemStyle": {
+                    "color": "#ec0000",
+                    "color0": "#00da3c",
+                    "borderColor": "#8a0000",
+                    "borderColor0": "#008f28",
+                },
+                "large": true,
+                "data": [[20, 34, 10, 38]],
+            })
+        );
+    }
+}
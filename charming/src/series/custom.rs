@@ -1,10 +1,11 @@
-use macros::serde_auto;
 use serde::{Deserialize, Serialize};
+use macros::serde_auto;
 
 use crate::{
     datatype::{CompositeValue, DataFrame, DataPoint, Dimension},
     element::{
-        ColorBy, CoordinateSystem, DimensionEncode, ItemStyle, LabelLayout, LabelLine, RawString,
+        impl_animation, Animation, Blur, ColorBy, CoordinateSystem, DimensionEncode, Emphasis,
+        ItemStyle, LabelLayout, LabelLine, RawString, Select, SelectedMode, Tooltip,
     },
 };
 
@@ -40,12 +41,23 @@ pub struct Custom {
 
     label_layout: Option<LabelLayout>,
 
-    selected_mode: Option<bool>,
+    emphasis: Option<Emphasis>,
+
+    blur: Option<Blur>,
+
+    select: Option<Select>,
+
+    selected_mode: Option<SelectedMode>,
 
     dimensions: Vec<Dimension>,
 
     encode: Option<DimensionEncode>,
 
+    #[serde(flatten)]
+    animation: Animation,
+
+    tooltip: Option<Tooltip>,
+
     data: DataFrame,
 }
 
@@ -66,9 +78,14 @@ impl Custom {
             item_style: None,
             label_line: None,
             label_layout: None,
+            emphasis: None,
+            blur: None,
+            select: None,
             selected_mode: None,
             dimensions: vec![],
             encode: None,
+            animation: Animation::new(),
+            tooltip: None,
             data: vec![],
         }
     }
@@ -123,6 +140,10 @@ impl Custom {
         self
     }
 
+    /// The function drawing each data item, either a JS function string or
+    /// a [`RenderGraphic`] compiled into one.
+    ///
+    /// [`RenderGraphic`]: crate::element::RenderGraphic
     pub fn render_item<R: Into<RawString>>(mut self, render_item: R) -> Self {
         self.render_item = Some(render_item.into());
         self
@@ -143,8 +164,23 @@ impl Custom {
         self
     }
 
-    pub fn selected_mode(mut self, selected_mode: bool) -> Self {
-        self.selected_mode = Some(selected_mode);
+    pub fn emphasis<E: Into<Emphasis>>(mut self, emphasis: E) -> Self {
+        self.emphasis = Some(emphasis.into());
+        self
+    }
+
+    pub fn blur<B: Into<Blur>>(mut self, blur: B) -> Self {
+        self.blur = Some(blur.into());
+        self
+    }
+
+    pub fn select<S: Into<Select>>(mut self, select: S) -> Self {
+        self.select = Some(select.into());
+        self
+    }
+
+    pub fn selected_mode<S: Into<SelectedMode>>(mut self, selected_mode: S) -> Self {
+        self.selected_mode = Some(selected_mode.into());
         self
     }
 
@@ -158,8 +194,15 @@ impl Custom {
         self
     }
 
+    pub fn tooltip<T: Into<Tooltip>>(mut self, tooltip: T) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
     pub fn data<D: Into<DataPoint>>(mut self, data: Vec<D>) -> Self {
         self.data = data.into_iter().map(|d| d.into()).collect();
         self
     }
 }
+
+impl_animation!(Custom);
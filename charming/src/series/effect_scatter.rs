@@ -4,8 +4,8 @@ use macros::serde_auto;
 use crate::{
     datatype::{DataFrame, DataPoint},
     element::{
-        Color, ColorBy, CoordinateSystem, Emphasis, ItemStyle, Label, LabelLayout, LabelLine,
-        Symbol,
+        impl_animation, Animation, Blur, Color, ColorBy, CoordinateSystem, Emphasis, ItemStyle,
+        Label, LabelLayout, LabelLine, Select, SelectedMode, Symbol, Tooltip,
     },
 };
 
@@ -132,6 +132,17 @@ pub struct EffectScatter {
 
     emphasis: Option<Emphasis>,
 
+    blur: Option<Blur>,
+
+    select: Option<Select>,
+
+    selected_mode: Option<SelectedMode>,
+
+    #[serde(flatten)]
+    animation: Animation,
+
+    tooltip: Option<Tooltip>,
+
     data: DataFrame,
 }
 
@@ -160,6 +171,11 @@ impl EffectScatter {
             label_layout: None,
             item_style: None,
             emphasis: None,
+            blur: None,
+            select: None,
+            selected_mode: None,
+            animation: Animation::new(),
+            tooltip: None,
             data: vec![],
         }
     }
@@ -274,8 +290,30 @@ impl EffectScatter {
         self
     }
 
+    pub fn blur<B: Into<Blur>>(mut self, blur: B) -> Self {
+        self.blur = Some(blur.into());
+        self
+    }
+
+    pub fn select<S: Into<Select>>(mut self, select: S) -> Self {
+        self.select = Some(select.into());
+        self
+    }
+
+    pub fn selected_mode<S: Into<SelectedMode>>(mut self, selected_mode: S) -> Self {
+        self.selected_mode = Some(selected_mode.into());
+        self
+    }
+
+    pub fn tooltip<T: Into<Tooltip>>(mut self, tooltip: T) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
     pub fn data<D: Into<DataPoint>>(mut self, data: Vec<D>) -> Self {
         self.data = data.into_iter().map(|d| d.into()).collect();
         self
     }
 }
+
+impl_animation!(EffectScatter);
@@ -3,7 +3,10 @@ use macros::serde_auto;
 
 use crate::{
     datatype::{CompositeValue, DataFrame, DataPoint},
-    element::{ColorBy, Emphasis, ItemStyle, Label, LabelLine, Orient, Sort},
+    element::{
+        impl_animation, Animation, Blur, ColorBy, Emphasis, ItemStyle, Label, LabelLine, Orient,
+        Select, SelectedMode, Sort, Tooltip,
+    },
 };
 
 #[serde_auto]
@@ -63,6 +66,16 @@ pub struct Funnel {
 
     emphasis: Option<Emphasis>,
 
+    blur: Option<Blur>,
+
+    select: Option<Select>,
+
+    selected_mode: Option<SelectedMode>,
+
+    #[serde(flatten)]
+    animation: Animation,
+
+    tooltip: Option<Tooltip>,
 
     data: DataFrame,
 }
@@ -92,6 +105,11 @@ impl Funnel {
             label_line: None,
             item_style: None,
             emphasis: None,
+            blur: None,
+            select: None,
+            selected_mode: None,
+            animation: Animation::new(),
+            tooltip: None,
             data: vec![],
         }
     }
@@ -206,8 +224,30 @@ impl Funnel {
         self
     }
 
+    pub fn blur<B: Into<Blur>>(mut self, blur: B) -> Self {
+        self.blur = Some(blur.into());
+        self
+    }
+
+    pub fn select<S: Into<Select>>(mut self, select: S) -> Self {
+        self.select = Some(select.into());
+        self
+    }
+
+    pub fn selected_mode<S: Into<SelectedMode>>(mut self, selected_mode: S) -> Self {
+        self.selected_mode = Some(selected_mode.into());
+        self
+    }
+
+    pub fn tooltip<T: Into<Tooltip>>(mut self, tooltip: T) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
     pub fn data<D: Into<DataPoint>>(mut self, data: Vec<D>) -> Self {
         self.data = data.into_iter().map(|d| d.into()).collect();
         self
     }
 }
+
+impl_animation!(Funnel);
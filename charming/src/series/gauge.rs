@@ -4,8 +4,8 @@ use macros::serde_auto;
 use crate::{
     datatype::{DataFrame, DataPoint},
     element::{
-        Anchor, AxisLabel, AxisLine, AxisTick, Color, ColorBy, Formatter, ItemStyle, Pointer,
-        SplitLine,
+        impl_animation, Anchor, Animation, AxisLabel, AxisLine, AxisTick, Color, ColorBy, Emphasis,
+        Formatter, ItemStyle, Pointer, SplitLine, Tooltip,
     },
 };
 
@@ -227,10 +227,16 @@ pub struct Gauge {
 
     anchor: Option<Anchor>,
 
+    emphasis: Option<Emphasis>,
+
     detail: Option<GaugeDetail>,
 
     title: Option<GaugeTitle>,
 
+    #[serde(flatten)]
+    animation: Animation,
+
+    tooltip: Option<Tooltip>,
 
     data: DataFrame,
 }
@@ -238,7 +244,7 @@ pub struct Gauge {
 impl Gauge {
     pub fn new() -> Self {
         Self {
-                        // type_: "gauge".to_string(),
+            // type_: "gauge".to_string(),
             id: None,
             name: None,
             color_by: None,
@@ -260,8 +266,11 @@ impl Gauge {
             split_line: None,
             pointer: None,
             anchor: None,
+            emphasis: None,
             detail: None,
             title: None,
+            animation: Animation::new(),
+            tooltip: None,
             data: vec![],
         }
     }
@@ -371,6 +380,11 @@ impl Gauge {
         self
     }
 
+    pub fn emphasis<E: Into<Emphasis>>(mut self, emphasis: E) -> Self {
+        self.emphasis = Some(emphasis.into());
+        self
+    }
+
     pub fn detail<D: Into<GaugeDetail>>(mut self, detail: D) -> Self {
         self.detail = Some(detail.into());
         self
@@ -381,8 +395,15 @@ impl Gauge {
         self
     }
 
+    pub fn tooltip<T: Into<Tooltip>>(mut self, tooltip: T) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
     pub fn data<D: Into<DataPoint>>(mut self, data: Vec<D>) -> Self {
         self.data = data.into_iter().map(|d| d.into()).collect();
         self
     }
 }
+
+impl_animation!(Gauge);
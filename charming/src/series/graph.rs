@@ -1,7 +1,13 @@
 use serde::{Deserialize, Serialize};
 use macros::serde_auto;
 
-use crate::element::{CoordinateSystem, Label, LabelLayout, LineStyle, ScaleLimit};
+use crate::{
+    datatype::CompositeValue,
+    element::{
+        impl_animation, Animation, Blur, CoordinateSystem, Cursor, Emphasis, ItemStyle, Label,
+        LabelLayout, LineStyle, ScaleLimit, Select, SelectedMode, Symbol, Tooltip,
+    },
+};
 
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -27,6 +33,8 @@ impl GraphLayoutCircular {
 pub struct GraphLayoutForce {
     init_layout: Option<String>,
 
+    repulsion: Option<CompositeValue>,
+
     gravity: Option<f64>,
 
     edge_length: Option<f64>,
@@ -40,6 +48,7 @@ impl GraphLayoutForce {
     pub fn new() -> Self {
         Self {
             init_layout: None,
+            repulsion: None,
             gravity: None,
             edge_length: None,
             layout_animation: None,
@@ -52,6 +61,13 @@ impl GraphLayoutForce {
         self
     }
 
+    /// Repulsion factor between nodes. A `[min, max]` pair maps node values
+    /// onto the range, so that larger nodes push harder.
+    pub fn repulsion<C: Into<CompositeValue>>(mut self, repulsion: C) -> Self {
+        self.repulsion = Some(repulsion.into());
+        self
+    }
+
     pub fn gravity(mut self, gravity: f64) -> Self {
         self.gravity = Some(gravity);
         self
@@ -151,15 +167,88 @@ impl GraphNodeLabel {
 pub struct GraphNode {
     pub id: String,
     pub name: String,
-    pub x: f64,
-    pub y: f64,
-    pub value: f64,
-    pub category: u64,
-    pub symbol_size: f64,
+    pub x: Option<f64>,
+    pub y: Option<f64>,
+    pub fixed: Option<bool>,
+    pub value: Option<f64>,
+    pub category: Option<u64>,
+    pub symbol: Option<Symbol>,
+    pub symbol_size: Option<f64>,
+    pub item_style: Option<ItemStyle>,
     #[serde(skip_deserializing)]
     pub label: Option<GraphNodeLabel>,
 }
 
+impl GraphNode {
+    /// Creates a node whose id and name are both `name`.
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        let name = name.into();
+        Self {
+            id: name.clone(),
+            name,
+            x: None,
+            y: None,
+            fixed: None,
+            value: None,
+            category: None,
+            symbol: None,
+            symbol_size: None,
+            item_style: None,
+            label: None,
+        }
+    }
+
+    pub fn id<S: Into<String>>(mut self, id: S) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Initial position of the node. Required by [`GraphLayout::None`], and
+    /// used as the starting point of the force layout.
+    pub fn position<F: Into<f64>>(mut self, x: F, y: F) -> Self {
+        self.x = Some(x.into());
+        self.y = Some(y.into());
+        self
+    }
+
+    /// Whether to pin the node at its position in the force layout.
+    pub fn fixed(mut self, fixed: bool) -> Self {
+        self.fixed = Some(fixed);
+        self
+    }
+
+    pub fn value<F: Into<f64>>(mut self, value: F) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Index of the node's entry in the graph categories.
+    pub fn category(mut self, category: u64) -> Self {
+        self.category = Some(category);
+        self
+    }
+
+    pub fn symbol(mut self, symbol: Symbol) -> Self {
+        self.symbol = Some(symbol);
+        self
+    }
+
+    pub fn symbol_size<F: Into<f64>>(mut self, symbol_size: F) -> Self {
+        self.symbol_size = Some(symbol_size.into());
+        self
+    }
+
+    pub fn item_style<I: Into<ItemStyle>>(mut self, item_style: I) -> Self {
+        self.item_style = Some(item_style.into());
+        self
+    }
+
+    pub fn label(mut self, label: GraphNodeLabel) -> Self {
+        self.label = Some(label);
+        self
+    }
+}
+
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -167,6 +256,46 @@ pub struct GraphLink {
     pub source: String,
     pub target: String,
     pub value: Option<f64>,
+    pub line_style: Option<LineStyle>,
+    pub label: Option<Label>,
+    pub ignore_force_layout: Option<bool>,
+}
+
+impl GraphLink {
+    /// Creates a link between two nodes, referenced by id or name.
+    pub fn new<S: Into<String>>(source: S, target: S) -> Self {
+        Self {
+            source: source.into(),
+            target: target.into(),
+            value: None,
+            line_style: None,
+            label: None,
+            ignore_force_layout: None,
+        }
+    }
+
+    pub fn value<F: Into<f64>>(mut self, value: F) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Style of this link, e.g. [`LineStyle::curveness`] to separate it from
+    /// a parallel link.
+    pub fn line_style<L: Into<LineStyle>>(mut self, line_style: L) -> Self {
+        self.line_style = Some(line_style.into());
+        self
+    }
+
+    pub fn label<L: Into<Label>>(mut self, label: L) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Whether the force layout ignores this link.
+    pub fn ignore_force_layout(mut self, ignore_force_layout: bool) -> Self {
+        self.ignore_force_layout = Some(ignore_force_layout);
+        self
+    }
 }
 
 #[serde_auto]
@@ -223,6 +352,45 @@ pub struct Graph {
 
     line_style: Option<LineStyle>,
 
+    item_style: Option<ItemStyle>,
+
+    emphasis: Option<Emphasis>,
+
+    blur: Option<Blur>,
+
+    select: Option<Select>,
+
+    selected_mode: Option<SelectedMode>,
+
+    draggable: Option<bool>,
+
+    zoom: Option<f64>,
+
+    cursor: Option<Cursor>,
+
+    symbol: Option<Symbol>,
+
+    symbol_size: Option<f64>,
+
+    edge_symbol: Option<(Symbol, Symbol)>,
+
+    edge_symbol_size: Option<(f64, f64)>,
+
+    edge_label: Option<Label>,
+
+    auto_curveness: Option<f64>,
+
+    focus_node_adjacency: Option<bool>,
+
+    #[serde(flatten)]
+    animation: Animation,
+
+    tooltip: Option<Tooltip>,
+
+    zlevel: Option<f64>,
+
+    z: Option<f64>,
+
     categories: Vec<GraphCategory>,
 
     links: Vec<GraphLink>,
@@ -250,6 +418,25 @@ impl Graph {
             label_layout: None,
             scale_limit: None,
             line_style: None,
+            item_style: None,
+            emphasis: None,
+            blur: None,
+            select: None,
+            selected_mode: None,
+            draggable: None,
+            zoom: None,
+            cursor: None,
+            symbol: None,
+            symbol_size: None,
+            edge_symbol: None,
+            edge_symbol_size: None,
+            edge_label: None,
+            auto_curveness: None,
+            focus_node_adjacency: None,
+            animation: Animation::new(),
+            tooltip: None,
+            zlevel: None,
+            z: None,
             categories: vec![],
             links: vec![],
             data: vec![],
@@ -341,6 +528,105 @@ impl Graph {
         self
     }
 
+    pub fn item_style<I: Into<ItemStyle>>(mut self, item_style: I) -> Self {
+        self.item_style = Some(item_style.into());
+        self
+    }
+
+    /// Highlight style. Use [`EmphasisFocus::Adjacency`] to fade everything
+    /// but the hovered node and its neighbours.
+    ///
+    /// [`EmphasisFocus::Adjacency`]: crate::element::EmphasisFocus::Adjacency
+    pub fn emphasis<E: Into<Emphasis>>(mut self, emphasis: E) -> Self {
+        self.emphasis = Some(emphasis.into());
+        self
+    }
+
+    pub fn blur<B: Into<Blur>>(mut self, blur: B) -> Self {
+        self.blur = Some(blur.into());
+        self
+    }
+
+    pub fn select<S: Into<Select>>(mut self, select: S) -> Self {
+        self.select = Some(select.into());
+        self
+    }
+
+    pub fn selected_mode<S: Into<SelectedMode>>(mut self, selected_mode: S) -> Self {
+        self.selected_mode = Some(selected_mode.into());
+        self
+    }
+
+    /// Whether nodes can be dragged. Only works with the force layout.
+    pub fn draggable(mut self, draggable: bool) -> Self {
+        self.draggable = Some(draggable);
+        self
+    }
+
+    pub fn zoom<F: Into<f64>>(mut self, zoom: F) -> Self {
+        self.zoom = Some(zoom.into());
+        self
+    }
+
+    pub fn cursor<C: Into<Cursor>>(mut self, cursor: C) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+
+    pub fn symbol(mut self, symbol: Symbol) -> Self {
+        self.symbol = Some(symbol);
+        self
+    }
+
+    pub fn symbol_size<F: Into<f64>>(mut self, symbol_size: F) -> Self {
+        self.symbol_size = Some(symbol_size.into());
+        self
+    }
+
+    /// Symbols at the source and target end of the edges, e.g.
+    /// `(Symbol::None, Symbol::Arrow)` for directed edges.
+    pub fn edge_symbol(mut self, edge_symbol: (Symbol, Symbol)) -> Self {
+        self.edge_symbol = Some(edge_symbol);
+        self
+    }
+
+    pub fn edge_symbol_size<F: Into<f64>>(mut self, edge_symbol_size: (F, F)) -> Self {
+        self.edge_symbol_size = Some((edge_symbol_size.0.into(), edge_symbol_size.1.into()));
+        self
+    }
+
+    pub fn edge_label<L: Into<Label>>(mut self, edge_label: L) -> Self {
+        self.edge_label = Some(edge_label.into());
+        self
+    }
+
+    /// Curves parallel edges between the same pair of nodes apart, using the
+    /// given number of curveness steps.
+    pub fn auto_curveness<F: Into<f64>>(mut self, auto_curveness: F) -> Self {
+        self.auto_curveness = Some(auto_curveness.into());
+        self
+    }
+
+    pub fn focus_node_adjacency(mut self, focus_node_adjacency: bool) -> Self {
+        self.focus_node_adjacency = Some(focus_node_adjacency);
+        self
+    }
+
+    pub fn tooltip<T: Into<Tooltip>>(mut self, tooltip: T) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    pub fn zlevel<F: Into<f64>>(mut self, zlevel: F) -> Self {
+        self.zlevel = Some(zlevel.into());
+        self
+    }
+
+    pub fn z<F: Into<f64>>(mut self, z: F) -> Self {
+        self.z = Some(z.into());
+        self
+    }
+
     pub fn data(mut self, data: GraphData) -> Self {
         self.data = data.nodes;
         self.links = data.links;
@@ -348,3 +634,51 @@ impl Graph {
         self
     }
 }
+
+impl_animation!(Graph);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn directed_graph() {
+        let graph = Graph::new()
+            .layout(GraphLayout::Force)
+            .force(GraphLayoutForce::new().repulsion(vec![50, 200]))
+            .draggable(true)
+            .edge_symbol((Symbol::None, Symbol::Arrow))
+            .edge_symbol_size((4, 10))
+            .auto_curveness(20)
+            .data(GraphData {
+                nodes: vec![
+                    GraphNode::new("gateway").position(0, 0).fixed(true),
+                    GraphNode::new("auth").value(3),
+                ],
+                links: vec![
+                    GraphLink::new("gateway", "auth").line_style(LineStyle::new().curveness(0.2))
+                ],
+                categories: vec![],
+            });
+        assert_eq!(
+            serde_json::to_value(&graph).unwrap(),
+            serde_json::json!({
+                "layout": "force",
+                "force": {"repulsion": [50, 200]},
+                "draggable": true,
+                "edgeSymbol": ["none", "arrow"],
+                "edgeSymbolSize": [4.0, 10.0],
+                "autoCurveness": 20.0,
+                "links": [{
+                    "source": "gateway",
+                    "target": "auth",
+                    "lineStyle": {"curveness": 0.2},
+                }],
+                "data": [
+                    {"id": "gateway", "name": "gateway", "x": 0.0, "y": 0.0, "fixed": true},
+                    {"id": "auth", "name": "auth", "value": 3.0},
+                ],
+            })
+        );
+    }
+}
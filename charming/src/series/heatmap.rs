@@ -2,10 +2,247 @@ use serde::{Deserialize, Serialize};
 use macros::serde_auto;
 
 use crate::{
-    datatype::DataFrame,
-    element::{CoordinateSystem, Emphasis, ItemStyle, Label},
+    component::{Axis, VisualMap},
+    datatype::{CompositeValue, DataFrame, NumericValue},
+    element::{
+        impl_animation, Animation, AxisType, Blur, CoordinateSystem, DimensionEncode, Emphasis,
+        ItemStyle, Label, MarkArea, MarkLine, MarkPoint, Select, SelectedMode, Tooltip,
+    },
+    EchartsError,
 };
 
+/// A heatmap cell with its own name and styles.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeatmapDataItem {
+    value: CompositeValue,
+
+    name: Option<String>,
+
+    item_style: Option<ItemStyle>,
+
+    label: Option<Label>,
+
+    emphasis: Option<Emphasis>,
+
+    blur: Option<Blur>,
+
+    select: Option<Select>,
+}
+
+impl HeatmapDataItem {
+    /// Creates a cell from its value, e.g. `val![x, y, value]` on a
+    /// cartesian grid or `val![date, value]` on a calendar.
+    pub fn new<C: Into<CompositeValue>>(value: C) -> Self {
+        Self {
+            value: value.into(),
+            name: None,
+            item_style: None,
+            label: None,
+            emphasis: None,
+            blur: None,
+            select: None,
+        }
+    }
+
+    pub fn name<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn item_style<S: Into<ItemStyle>>(mut self, item_style: S) -> Self {
+        self.item_style = Some(item_style.into());
+        self
+    }
+
+    pub fn label<L: Into<Label>>(mut self, label: L) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn emphasis<E: Into<Emphasis>>(mut self, emphasis: E) -> Self {
+        self.emphasis = Some(emphasis.into());
+        self
+    }
+
+    pub fn blur<B: Into<Blur>>(mut self, blur: B) -> Self {
+        self.blur = Some(blur.into());
+        self
+    }
+
+    pub fn select<S: Into<Select>>(mut self, select: S) -> Self {
+        self.select = Some(select.into());
+        self
+    }
+}
+
+/// A heatmap cell, either a bare value such as `[x, y, value]` or an item
+/// with its own styles. Cartesian cells convert from `(x, y, value)` tuples
+/// or `df![x, y, value]` frames, and calendar cells are built with
+/// [`HeatmapData::calendar`]; a `"-"` or `None` value leaves the cell empty.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum HeatmapData {
+    Value(CompositeValue),
+    Item(Box<HeatmapDataItem>),
+}
+
+impl<X, Y, V> From<(X, Y, V)> for HeatmapData
+where
+    X: Into<CompositeValue>,
+    Y: Into<CompositeValue>,
+    V: Into<CompositeValue>,
+{
+    fn from((x, y, value): (X, Y, V)) -> Self {
+        HeatmapData::Value(CompositeValue::Array(vec![
+            x.into(),
+            y.into(),
+            value.into(),
+        ]))
+    }
+}
+
+impl HeatmapData {
+    /// Creates a calendar cell from its date, such as `"2017-01-01"`, and
+    /// value.
+    pub fn calendar<D: Into<CompositeValue>, V: Into<CompositeValue>>(date: D, value: V) -> Self {
+        HeatmapData::Value(CompositeValue::Array(vec![date.into(), value.into()]))
+    }
+}
+
+/// Items of the frame keep only their values.
+impl From<DataFrame> for HeatmapData {
+    fn from(frame: DataFrame) -> Self {
+        HeatmapData::Value(CompositeValue::Array(
+            frame.into_iter().map(|point| point.into_value()).collect(),
+        ))
+    }
+}
+
+impl From<HeatmapDataItem> for HeatmapData {
+    fn from(item: HeatmapDataItem) -> Self {
+        HeatmapData::Item(Box::new(item))
+    }
+}
+
+/// A matrix of values with labelled rows and columns, such as a correlation
+/// matrix or an hour-by-weekday activity map, from which the category axes,
+/// visual map and series of a cartesian heatmap are built.
+///
+/// Rows are laid out along the y axis from the bottom up, and columns along
+/// the x axis.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeatmapMatrix {
+    x_labels: Vec<String>,
+
+    y_labels: Vec<String>,
+
+    values: Vec<Vec<CompositeValue>>,
+}
+
+impl HeatmapMatrix {
+    /// Creates a matrix from its rows. Use `None` or `"-"` for empty cells.
+    /// Fails if the rows differ in length.
+    pub fn new<V: Into<CompositeValue>>(rows: Vec<Vec<V>>) -> Result<Self, EchartsError> {
+        if let Some(first) = rows.first() {
+            if let Some(row) = rows.iter().position(|row| row.len() != first.len()) {
+                return Err(EchartsError::DatasetError(format!(
+                    "row {} of the heatmap matrix has {} cells, expected {}",
+                    row,
+                    rows[row].len(),
+                    first.len()
+                )));
+            }
+        }
+        Ok(Self {
+            x_labels: vec![],
+            y_labels: vec![],
+            values: rows
+                .into_iter()
+                .map(|row| row.into_iter().map(|v| v.into()).collect())
+                .collect(),
+        })
+    }
+
+    /// Labels of the columns. Fails unless there is one label per column.
+    pub fn x_labels<S: Into<String>>(mut self, x_labels: Vec<S>) -> Result<Self, EchartsError> {
+        let columns = self.values.first().map_or(0, |row| row.len());
+        if x_labels.len() != columns {
+            return Err(EchartsError::DatasetError(format!(
+                "heatmap matrix has {} columns but {} x labels",
+                columns,
+                x_labels.len()
+            )));
+        }
+        self.x_labels = x_labels.into_iter().map(|s| s.into()).collect();
+        Ok(self)
+    }
+
+    /// Labels of the rows. Fails unless there is one label per row.
+    pub fn y_labels<S: Into<String>>(mut self, y_labels: Vec<S>) -> Result<Self, EchartsError> {
+        if y_labels.len() != self.values.len() {
+            return Err(EchartsError::DatasetError(format!(
+                "heatmap matrix has {} rows but {} y labels",
+                self.values.len(),
+                y_labels.len()
+            )));
+        }
+        self.y_labels = y_labels.into_iter().map(|s| s.into()).collect();
+        Ok(self)
+    }
+
+    /// Category axis of the column labels.
+    pub fn x_axis(&self) -> Axis {
+        Axis::new()
+            .type_(AxisType::Category)
+            .data(self.x_labels.clone())
+    }
+
+    /// Category axis of the row labels.
+    pub fn y_axis(&self) -> Axis {
+        Axis::new()
+            .type_(AxisType::Category)
+            .data(self.y_labels.clone())
+    }
+
+    /// Continuous visual map spanning the numeric values of the matrix.
+    pub fn visual_map(&self) -> VisualMap {
+        let mut visual_map = VisualMap::new().calculable(true);
+        let numbers = self.values.iter().flatten().filter_map(|v| match v {
+            CompositeValue::Number(NumericValue::Integer(n)) => Some(*n as f64),
+            CompositeValue::Number(NumericValue::Float(n)) => Some(*n),
+            _ => None,
+        });
+        if let Some((min, max)) = numbers.fold(None, |range, n| match range {
+            None => Some((n, n)),
+            Some((min, max)) => Some((f64::min(min, n), f64::max(max, n))),
+        }) {
+            visual_map = visual_map.min(min).max(max);
+        }
+        visual_map
+    }
+
+    /// Cells of the matrix as `[column, row, value]` items.
+    pub fn data(&self) -> Vec<HeatmapData> {
+        self.values
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .map(move |(x, value)| (x as i32, y as i32, value.clone()).into())
+            })
+            .collect()
+    }
+
+    /// Heatmap series holding the cells of the matrix.
+    pub fn series(&self) -> Heatmap {
+        Heatmap::new().data(self.data())
+    }
+}
+
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -42,8 +279,34 @@ pub struct Heatmap {
 
     emphasis: Option<Emphasis>,
 
+    blur: Option<Blur>,
+
+    select: Option<Select>,
+
+    selected_mode: Option<SelectedMode>,
+
+    dataset_index: Option<f64>,
 
-    data: Vec<DataFrame>,
+    encode: Option<DimensionEncode>,
+
+    mark_point: Option<MarkPoint>,
+
+    mark_line: Option<MarkLine>,
+
+    mark_area: Option<MarkArea>,
+
+    zlevel: Option<f64>,
+
+    z: Option<f64>,
+
+    silent: Option<bool>,
+
+    #[serde(flatten)]
+    animation: Animation,
+
+    tooltip: Option<Tooltip>,
+
+    data: Vec<HeatmapData>,
 }
 
 impl Heatmap {
@@ -65,6 +328,19 @@ impl Heatmap {
             label: None,
             item_style: None,
             emphasis: None,
+            blur: None,
+            select: None,
+            selected_mode: None,
+            dataset_index: None,
+            encode: None,
+            mark_point: None,
+            mark_line: None,
+            mark_area: None,
+            zlevel: None,
+            z: None,
+            silent: None,
+            animation: Animation::new(),
+            tooltip: None,
             data: vec![],
         }
     }
@@ -149,8 +425,117 @@ impl Heatmap {
         self
     }
 
-    pub fn data<S: Into<DataFrame>>(mut self, data: Vec<S>) -> Self {
+    pub fn blur<B: Into<Blur>>(mut self, blur: B) -> Self {
+        self.blur = Some(blur.into());
+        self
+    }
+
+    pub fn select<S: Into<Select>>(mut self, select: S) -> Self {
+        self.select = Some(select.into());
+        self
+    }
+
+    pub fn selected_mode<S: Into<SelectedMode>>(mut self, selected_mode: S) -> Self {
+        self.selected_mode = Some(selected_mode.into());
+        self
+    }
+
+    pub fn dataset_index<F: Into<f64>>(mut self, dataset_index: F) -> Self {
+        self.dataset_index = Some(dataset_index.into());
+        self
+    }
+
+    pub fn encode<D: Into<DimensionEncode>>(mut self, encode: D) -> Self {
+        self.encode = Some(encode.into());
+        self
+    }
+
+    pub fn mark_point<M: Into<MarkPoint>>(mut self, mark_point: M) -> Self {
+        self.mark_point = Some(mark_point.into());
+        self
+    }
+
+    pub fn mark_line<M: Into<MarkLine>>(mut self, mark_line: M) -> Self {
+        self.mark_line = Some(mark_line.into());
+        self
+    }
+
+    pub fn mark_area<M: Into<MarkArea>>(mut self, mark_area: M) -> Self {
+        self.mark_area = Some(mark_area.into());
+        self
+    }
+
+    pub fn zlevel<F: Into<f64>>(mut self, zlevel: F) -> Self {
+        self.zlevel = Some(zlevel.into());
+        self
+    }
+
+    pub fn z<F: Into<f64>>(mut self, z: F) -> Self {
+        self.z = Some(z.into());
+        self
+    }
+
+    pub fn silent(mut self, silent: bool) -> Self {
+        self.silent = Some(silent);
+        self
+    }
+
+    pub fn tooltip<T: Into<Tooltip>>(mut self, tooltip: T) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    pub fn data<D: Into<HeatmapData>>(mut self, data: Vec<D>) -> Self {
         self.data = data.into_iter().map(|d| d.into()).collect();
         self
     }
 }
+
+impl_animation!(Heatmap);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::df;
+
+    #[test]
+    fn heatmap_matrix() {
+        let matrix = HeatmapMatrix::new(vec![vec![Some(1.0), None], vec![Some(-0.5), Some(1.0)]])
+            .and_then(|m| m.x_labels(vec!["cpu", "mem"]))
+            .and_then(|m| m.y_labels(vec!["cpu", "mem"]))
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(matrix.series()).unwrap()["data"],
+            serde_json::json!([[0, 0, 1.0], [1, 0, null], [0, 1, -0.5], [1, 1, 1.0]])
+        );
+        assert_eq!(
+            serde_json::to_value(matrix.visual_map()).unwrap(),
+            serde_json::json!({ "min": -0.5, "max": 1.0, "calculable": true })
+        );
+        assert_eq!(
+            serde_json::to_value(matrix.y_axis()).unwrap(),
+            serde_json::json!({ "type": "category", "data": ["cpu", "mem"] })
+        );
+    }
+
+    #[test]
+    fn heatmap_matrix_shape_mismatch() {
+        assert!(HeatmapMatrix::new(vec![vec![1, 2], vec![3]]).is_err());
+        let matrix = HeatmapMatrix::new(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        assert!(matrix.clone().x_labels(vec!["a", "b", "c"]).is_err());
+        assert!(matrix.y_labels(vec!["a"]).is_err());
+    }
+
+    #[test]
+    fn heatmap_data() {
+        let heatmap = Heatmap::new().data(vec![
+            HeatmapData::calendar("2017-01-01", 3),
+            df![0, 1, 5].into(),
+            (1, 1, "-").into(),
+        ]);
+        assert_eq!(
+            serde_json::to_value(heatmap).unwrap()["data"],
+            serde_json::json!([["2017-01-01", 3], [0, 1, 5], [1, 1, "-"]])
+        );
+    }
+}
@@ -0,0 +1,88 @@
+/// A node of the tree built by [`nest_paths`], converted by each
+/// hierarchical series into its own node type.
+pub(crate) struct PathNode {
+    pub(crate) name: String,
+
+    pub(crate) value: Option<f64>,
+
+    pub(crate) children: Vec<PathNode>,
+}
+
+/// Nests `(path, value)` pairs into trees. Paths sharing a prefix share the
+/// nodes of that prefix, kept in order of first appearance, and each value
+/// is set on the last node of its path. Values of a repeated path are summed,
+/// and empty paths are ignored.
+pub(crate) fn nest_paths<P, S, F>(paths: Vec<(P, F)>) -> Vec<PathNode>
+where
+    P: IntoIterator<Item = S>,
+    S: Into<String>,
+    F: Into<f64>,
+{
+    let mut roots = vec![];
+    for (path, value) in paths {
+        insert(
+            &mut roots,
+            path.into_iter().map(|s| s.into()).peekable(),
+            value.into(),
+        );
+    }
+    roots
+}
+
+fn insert<I: Iterator<Item = String>>(
+    nodes: &mut Vec<PathNode>,
+    mut segments: std::iter::Peekable<I>,
+    value: f64,
+) {
+    let name = match segments.next() {
+        Some(name) => name,
+        None => return,
+    };
+    let index = match nodes.iter().position(|n| n.name == name) {
+        Some(index) => index,
+        None => {
+            nodes.push(PathNode {
+                name,
+                value: None,
+                children: vec![],
+            });
+            nodes.len() - 1
+        }
+    };
+    if segments.peek().is_none() {
+        let node = &mut nodes[index];
+        node.value = Some(node.value.unwrap_or(0.0) + value);
+    } else {
+        insert(&mut nodes[index].children, segments, value);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn nest_paths_sums_repeated_paths() {
+        let roots = nest_paths(vec![
+            (vec!["a", "b"], 1),
+            (vec!["a", "c"], 2),
+            (vec!["a", "b"], 3),
+        ]);
+        assert_eq!(roots.len(), 1);
+        assert_eq!(roots[0].name, "a");
+        assert_eq!(roots[0].value, None);
+        let children: Vec<_> = roots[0]
+            .children
+            .iter()
+            .map(|n| (n.name.as_str(), n.value))
+            .collect();
+        assert_eq!(children, vec![("b", Some(4.0)), ("c", Some(2.0))]);
+    }
+
+    #[test]
+    fn nest_paths_ignores_empty_paths() {
+        let roots = nest_paths(vec![(vec![], 1), (vec!["a"], 2)]);
+        assert_eq!(roots.len(), 1);
+        assert_eq!((roots[0].name.as_str(), roots[0].value), ("a", Some(2.0)));
+    }
+}
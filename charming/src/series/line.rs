@@ -4,11 +4,31 @@ use macros::serde_auto;
 use crate::{
     datatype::{DataFrame, DataPoint},
     element::{
-        AreaStyle, CoordinateSystem, DimensionEncode, Emphasis, ItemStyle, LineStyle, MarkArea,
-        MarkLine, MarkPoint, Symbol,
+        impl_animation, Animation, AreaStyle, Blur, ColorBy, CoordinateSystem, DimensionEncode,
+        Emphasis, ItemStyle, Label, LineStyle, MarkArea, MarkLine, MarkPoint, Sampling, Select,
+        SelectedMode, SeriesLayoutBy, Symbol, Tooltip,
     },
 };
 
+/// Where the step of a step line chart turns.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LineStep {
+    Start,
+    Middle,
+    End,
+}
+
+/// The axis along which a smoothed line stays monotone.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SmoothMonotone {
+    X,
+    Y,
+}
+
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -17,16 +37,40 @@ pub struct Line {
 
     name: Option<String>,
 
+    color_by: Option<ColorBy>,
+
     coordinate_system: Option<CoordinateSystem>,
 
+    x_axis_index: Option<f64>,
+
+    y_axis_index: Option<f64>,
+
+    polar_index: Option<f64>,
+
     symbol: Option<Symbol>,
 
     symbol_size: Option<f64>,
 
+    symbol_rotate: Option<f64>,
+
     show_symbol: Option<bool>,
 
+    show_all_symbol: Option<bool>,
+
+    legend_hover_link: Option<bool>,
+
     stack: Option<String>,
 
+    clip: Option<bool>,
+
+    connect_nulls: Option<bool>,
+
+    step: Option<LineStep>,
+
+    label: Option<Label>,
+
+    end_label: Option<Label>,
+
     line_style: Option<LineStyle>,
 
     area_style: Option<AreaStyle>,
@@ -35,22 +79,44 @@ pub struct Line {
 
     emphasis: Option<Emphasis>,
 
+    blur: Option<Blur>,
+
+    select: Option<Select>,
+
+    selected_mode: Option<SelectedMode>,
+
     smooth: Option<f64>,
 
+    smooth_monotone: Option<SmoothMonotone>,
+
+    sampling: Option<Sampling>,
+
+    progressive: Option<f64>,
+
+    progressive_threshold: Option<f64>,
+
     mark_point: Option<MarkPoint>,
 
     mark_line: Option<MarkLine>,
 
     mark_area: Option<MarkArea>,
 
+    dataset_index: Option<f64>,
+
     dataset_id: Option<String>,
 
+    series_layout_by: Option<SeriesLayoutBy>,
+
     encode: Option<DimensionEncode>,
 
-    x_axis_index: Option<f64>,
+    #[serde(flatten)]
+    animation: Animation,
 
-    y_axis_index: Option<f64>,
+    tooltip: Option<Tooltip>,
+
+    zlevel: Option<f64>,
 
+    z: Option<f64>,
 
     data: DataFrame,
 }
@@ -60,23 +126,46 @@ impl Line {
         Self {
             id: None,
             name: None,
+            color_by: None,
             coordinate_system: None,
+            x_axis_index: None,
+            y_axis_index: None,
+            polar_index: None,
             symbol: None,
             symbol_size: None,
+            symbol_rotate: None,
             show_symbol: None,
+            show_all_symbol: None,
+            legend_hover_link: None,
             stack: None,
+            clip: None,
+            connect_nulls: None,
+            step: None,
+            label: None,
+            end_label: None,
             line_style: None,
             area_style: None,
             item_style: None,
             emphasis: None,
+            blur: None,
+            select: None,
+            selected_mode: None,
             smooth: None,
+            smooth_monotone: None,
+            sampling: None,
+            progressive: None,
+            progressive_threshold: None,
             mark_point: None,
             mark_line: None,
             mark_area: None,
+            dataset_index: None,
             dataset_id: None,
+            series_layout_by: None,
             encode: None,
-            x_axis_index: None,
-            y_axis_index: None,
+            animation: Animation::new(),
+            tooltip: None,
+            zlevel: None,
+            z: None,
             data: vec![],
         }
     }
@@ -92,11 +181,31 @@ impl Line {
         self
     }
 
+    pub fn color_by<C: Into<ColorBy>>(mut self, color_by: C) -> Self {
+        self.color_by = Some(color_by.into());
+        self
+    }
+
     pub fn coordinate_system<C: Into<CoordinateSystem>>(mut self, coordinate_system: C) -> Self {
         self.coordinate_system = Some(coordinate_system.into());
         self
     }
 
+    pub fn x_axis_index<F: Into<f64>>(mut self, x_axis_index: F) -> Self {
+        self.x_axis_index = Some(x_axis_index.into());
+        self
+    }
+
+    pub fn y_axis_index<F: Into<f64>>(mut self, y_axis_index: F) -> Self {
+        self.y_axis_index = Some(y_axis_index.into());
+        self
+    }
+
+    pub fn polar_index<F: Into<f64>>(mut self, polar_index: F) -> Self {
+        self.polar_index = Some(polar_index.into());
+        self
+    }
+
     pub fn symbol<S: Into<Symbol>>(mut self, symbol: S) -> Self {
         self.symbol = Some(symbol.into());
         self
@@ -107,16 +216,63 @@ impl Line {
         self
     }
 
+    pub fn symbol_rotate<F: Into<f64>>(mut self, symbol_rotate: F) -> Self {
+        self.symbol_rotate = Some(symbol_rotate.into());
+        self
+    }
+
     pub fn show_symbol(mut self, show_symbol: bool) -> Self {
         self.show_symbol = Some(show_symbol);
         self
     }
 
+    /// Whether to show all symbols, even when they would overlap on a
+    /// category axis.
+    pub fn show_all_symbol(mut self, show_all_symbol: bool) -> Self {
+        self.show_all_symbol = Some(show_all_symbol);
+        self
+    }
+
+    pub fn legend_hover_link(mut self, legend_hover_link: bool) -> Self {
+        self.legend_hover_link = Some(legend_hover_link);
+        self
+    }
+
     pub fn stack<S: Into<String>>(mut self, stack: S) -> Self {
         self.stack = Some(stack.into());
         self
     }
 
+    /// Whether to clip the overflowing parts of the line to the coordinate
+    /// system.
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = Some(clip);
+        self
+    }
+
+    /// Whether to connect the line across null points.
+    pub fn connect_nulls(mut self, connect_nulls: bool) -> Self {
+        self.connect_nulls = Some(connect_nulls);
+        self
+    }
+
+    /// Draws a step line that turns at the given position.
+    pub fn step<S: Into<LineStep>>(mut self, step: S) -> Self {
+        self.step = Some(step.into());
+        self
+    }
+
+    pub fn label<L: Into<Label>>(mut self, label: L) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Label at the end of the line, commonly used by line racing charts.
+    pub fn end_label<L: Into<Label>>(mut self, end_label: L) -> Self {
+        self.end_label = Some(end_label.into());
+        self
+    }
+
     pub fn line_style<L: Into<LineStyle>>(mut self, line_style: L) -> Self {
         self.line_style = Some(line_style.into());
         self
@@ -137,12 +293,49 @@ impl Line {
         self
     }
 
+    pub fn blur<B: Into<Blur>>(mut self, blur: B) -> Self {
+        self.blur = Some(blur.into());
+        self
+    }
+
+    pub fn select<S: Into<Select>>(mut self, select: S) -> Self {
+        self.select = Some(select.into());
+        self
+    }
+
+    pub fn selected_mode<S: Into<SelectedMode>>(mut self, selected_mode: S) -> Self {
+        self.selected_mode = Some(selected_mode.into());
+        self
+    }
+
     /// Smoothness.
     pub fn smooth<F: Into<f64>>(mut self, smooth: F) -> Self {
         self.smooth = Some(smooth.into());
         self
     }
 
+    pub fn smooth_monotone<S: Into<SmoothMonotone>>(mut self, smooth_monotone: S) -> Self {
+        self.smooth_monotone = Some(smooth_monotone.into());
+        self
+    }
+
+    /// The down-sampling strategy used when the amount of data is much larger
+    /// than the number of pixels.
+    pub fn sampling<S: Into<Sampling>>(mut self, sampling: S) -> Self {
+        self.sampling = Some(sampling.into());
+        self
+    }
+
+    pub fn progressive<F: Into<f64>>(mut self, progressive: F) -> Self {
+        self.progressive = Some(progressive.into());
+        self
+    }
+
+    pub fn progressive_threshold<F: Into<f64>>(mut self, progressive_threshold: F) -> Self {
+        self.progressive_threshold = Some(progressive_threshold.into());
+        self
+    }
+
     pub fn mark_point<M: Into<MarkPoint>>(mut self, mark_point: M) -> Self {
         self.mark_point = Some(mark_point.into());
         self
@@ -158,23 +351,39 @@ impl Line {
         self
     }
 
+    pub fn dataset_index<F: Into<f64>>(mut self, dataset_index: F) -> Self {
+        self.dataset_index = Some(dataset_index.into());
+        self
+    }
+
     pub fn dataset_id<S: Into<String>>(mut self, dataset_id: S) -> Self {
         self.dataset_id = Some(dataset_id.into());
         self
     }
 
+    pub fn series_layout_by<S: Into<SeriesLayoutBy>>(mut self, series_layout_by: S) -> Self {
+        self.series_layout_by = Some(series_layout_by.into());
+        self
+    }
+
     pub fn encode<E: Into<DimensionEncode>>(mut self, encode: E) -> Self {
         self.encode = Some(encode.into());
         self
     }
 
-    pub fn x_axis_index<F: Into<f64>>(mut self, x_axis_index: F) -> Self {
-        self.x_axis_index = Some(x_axis_index.into());
+    /// Tooltip settings of this series, overriding the global tooltip.
+    pub fn tooltip<T: Into<Tooltip>>(mut self, tooltip: T) -> Self {
+        self.tooltip = Some(tooltip.into());
         self
     }
 
-    pub fn y_axis_index<F: Into<f64>>(mut self, y_axis_index: F) -> Self {
-        self.y_axis_index = Some(y_axis_index.into());
+    pub fn zlevel<F: Into<f64>>(mut self, zlevel: F) -> Self {
+        self.zlevel = Some(zlevel.into());
+        self
+    }
+
+    pub fn z<F: Into<f64>>(mut self, z: F) -> Self {
+        self.z = Some(z.into());
         self
     }
 
@@ -183,3 +392,63 @@ impl Line {
         self
     }
 }
+
+impl_animation!(Line);
+
+#[cfg(test)]
+mod test {
+    use crate::element::Trigger;
+
+    use super::*;
+
+    #[test]
+    fn step_line() {
+        let line = Line::new()
+            .name("state")
+            .step(LineStep::Middle)
+            .connect_nulls(true)
+            .sampling(Sampling::Lttb)
+            .series_layout_by(SeriesLayoutBy::Column)
+            .data(vec![Some(1), None, Some(0)]);
+        assert_eq!(
+            serde_json::to_value(&line).unwrap(),
+            serde_json::json!({
+                "name": "state",
+                "connectNulls": true,
+                "step": "middle",
+                "sampling": "lttb",
+                "seriesLayoutBy": "column",
+                "data": [1, null, 0],
+            })
+        );
+    }
+
+    #[test]
+    fn racing_line() {
+        let line = Line::new()
+            .show_symbol(false)
+            .smooth(0.3)
+            .smooth_monotone(SmoothMonotone::X)
+            .end_label(
+                Label::new()
+                    .show(true)
+                    .formatter("{a}: {c}")
+                    .value_animation(true),
+            )
+            .tooltip(Tooltip::new().trigger(Trigger::Axis))
+            .clip(false)
+            .z(3);
+        assert_eq!(
+            serde_json::to_value(&line).unwrap(),
+            serde_json::json!({
+                "showSymbol": false,
+                "clip": false,
+                "endLabel": {"show": true, "formatter": "{a}: {c}", "valueAnimation": true},
+                "smooth": 0.3,
+                "smoothMonotone": "x",
+                "tooltip": {"trigger": "axis"},
+                "z": 3.0,
+            })
+        );
+    }
+}
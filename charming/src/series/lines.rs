@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
 use macros::serde_auto;
 
-use crate::element::{ColorBy, CoordinateSystem, Emphasis, Label, LabelLayout, LineStyle, Symbol};
+use crate::element::{
+    Blur, ColorBy, CoordinateSystem, Emphasis, Label, LabelLayout, LineStyle, Select, SelectedMode,
+    Symbol,
+};
 
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -40,4 +43,10 @@ pub struct Lines {
     label_layout: Option<LabelLayout>,
 
     emphasis: Option<Emphasis>,
+
+    blur: Option<Blur>,
+
+    select: Option<Select>,
+
+    selected_mode: Option<SelectedMode>,
 }
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use macros::serde_auto;
 
+mod hierarchy;
+
 pub mod bar;
 pub mod bar3d;
 pub mod boxplot;
@@ -3,7 +3,10 @@ use macros::serde_auto;
 
 use crate::{
     datatype::{DataFrame, DataPoint},
-    element::{ColorBy, CoordinateSystem, Emphasis, LineStyle},
+    element::{
+        impl_animation, Animation, Blur, ColorBy, CoordinateSystem, Emphasis, LineStyle, Select,
+        SelectedMode, Tooltip,
+    },
 };
 
 #[serde_auto]
@@ -32,6 +35,12 @@ pub struct Parallel {
 
     emphasis: Option<Emphasis>,
 
+    blur: Option<Blur>,
+
+    select: Option<Select>,
+
+    selected_mode: Option<SelectedMode>,
+
     inactive_opacity: Option<f64>,
 
     active_opacity: Option<f64>,
@@ -46,6 +55,10 @@ pub struct Parallel {
 
     progressive_chunk_mode: Option<ProgressiveChunkMode>,
 
+    #[serde(flatten)]
+    animation: Animation,
+
+    tooltip: Option<Tooltip>,
 
     data: DataFrame,
 }
@@ -60,6 +73,9 @@ impl Parallel {
             color_by: None,
             line_style: None,
             emphasis: None,
+            blur: None,
+            select: None,
+            selected_mode: None,
             inactive_opacity: None,
             active_opacity: None,
             realtime: None,
@@ -67,6 +83,8 @@ impl Parallel {
             progressive: None,
             progressive_threshold: None,
             progressive_chunk_mode: None,
+            animation: Animation::new(),
+            tooltip: None,
             data: vec![],
         }
     }
@@ -106,6 +124,21 @@ impl Parallel {
         self
     }
 
+    pub fn blur<B: Into<Blur>>(mut self, blur: B) -> Self {
+        self.blur = Some(blur.into());
+        self
+    }
+
+    pub fn select<S: Into<Select>>(mut self, select: S) -> Self {
+        self.select = Some(select.into());
+        self
+    }
+
+    pub fn selected_mode<S: Into<SelectedMode>>(mut self, selected_mode: S) -> Self {
+        self.selected_mode = Some(selected_mode.into());
+        self
+    }
+
     pub fn inactive_opacity<F: Into<f64>>(mut self, inactive_opacity: F) -> Self {
         self.inactive_opacity = Some(inactive_opacity.into());
         self
@@ -144,8 +177,15 @@ impl Parallel {
         self
     }
 
+    pub fn tooltip<T: Into<Tooltip>>(mut self, tooltip: T) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
     pub fn data<D: Into<DataPoint>>(mut self, data: Vec<D>) -> Self {
         self.data = data.into_iter().map(|d| d.into()).collect();
         self
     }
 }
+
+impl_animation!(Parallel);
@@ -4,7 +4,8 @@ use macros::serde_auto;
 use crate::{
     datatype::DataFrame,
     element::{
-        ColorBy, CoordinateSystem, Cursor, Emphasis, ItemStyle, Label, LabelLayout, LabelLine,
+        impl_animation, Animation, Blur, ColorBy, CoordinateSystem, Cursor, Emphasis, ItemStyle,
+        Label, LabelLayout, LabelLine, Select, SelectedMode, Tooltip,
     },
 };
 
@@ -38,10 +39,20 @@ pub struct PictorialBar {
 
     emphasis: Option<Emphasis>,
 
+    blur: Option<Blur>,
+
+    select: Option<Select>,
+
+    selected_mode: Option<SelectedMode>,
+
     symbol_clip: Option<bool>,
 
     symbol_bounding_data: Option<f64>,
 
+    #[serde(flatten)]
+    animation: Animation,
+
+    tooltip: Option<Tooltip>,
 
     data: Vec<DataFrame>,
 }
@@ -62,8 +73,13 @@ impl PictorialBar {
             label_layout: None,
             item_style: None,
             emphasis: None,
+            blur: None,
+            select: None,
+            selected_mode: None,
             symbol_clip: None,
             symbol_bounding_data: None,
+            animation: Animation::new(),
+            tooltip: None,
             data: vec![],
         }
     }
@@ -133,6 +149,21 @@ impl PictorialBar {
         self
     }
 
+    pub fn blur<B: Into<Blur>>(mut self, blur: B) -> Self {
+        self.blur = Some(blur.into());
+        self
+    }
+
+    pub fn select<S: Into<Select>>(mut self, select: S) -> Self {
+        self.select = Some(select.into());
+        self
+    }
+
+    pub fn selected_mode<S: Into<SelectedMode>>(mut self, selected_mode: S) -> Self {
+        self.selected_mode = Some(selected_mode.into());
+        self
+    }
+
     pub fn symbol_clip(mut self, symbol_clip: bool) -> Self {
         self.symbol_clip = Some(symbol_clip);
         self
@@ -143,8 +174,15 @@ impl PictorialBar {
         self
     }
 
+    pub fn tooltip<T: Into<Tooltip>>(mut self, tooltip: T) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
     pub fn data<D: Into<DataFrame>>(mut self, data: Vec<D>) -> Self {
         self.data = data.into_iter().map(|d| d.into()).collect();
         self
     }
 }
+
+impl_animation!(PictorialBar);
@@ -3,7 +3,10 @@ use macros::serde_auto;
 
 use crate::{
     datatype::{CompositeValue, DataFrame, DataPoint},
-    element::{ColorBy, CoordinateSystem, Emphasis, ItemStyle, Label, LabelLine},
+    element::{
+        impl_animation, Animation, Blur, ColorBy, CoordinateSystem, DimensionEncode, Emphasis,
+        ItemStyle, Label, LabelLayout, LabelLine, Select, SelectedMode, SeriesLayoutBy, Tooltip,
+    },
 };
 
 #[serde_auto]
@@ -26,13 +29,13 @@ pub struct Pie {
 
     legend_hover_link: Option<bool>,
 
-    coordiate_system: Option<CoordinateSystem>,
+    coordinate_system: Option<CoordinateSystem>,
 
     geo_index: Option<f64>,
 
     calendar_index: Option<f64>,
 
-    selected_mode: Option<bool>,
+    selected_mode: Option<SelectedMode>,
 
     selected_offset: Option<f64>,
 
@@ -42,20 +45,58 @@ pub struct Pie {
 
     start_angle: Option<f64>,
 
+    end_angle: Option<f64>,
+
+    min_angle: Option<f64>,
+
+    min_show_label_angle: Option<f64>,
+
+    pad_angle: Option<f64>,
+
+    percent_precision: Option<f64>,
+
+    still_show_zero_sum: Option<bool>,
+
+    show_empty_circle: Option<bool>,
+
+    empty_circle_style: Option<ItemStyle>,
+
     rose_type: Option<PieRoseType>,
 
     label: Option<Label>,
 
     label_line: Option<LabelLine>,
 
+    label_layout: Option<LabelLayout>,
+
     item_style: Option<ItemStyle>,
 
     emphasis: Option<Emphasis>,
 
+    blur: Option<Blur>,
+
+    select: Option<Select>,
+
     center: Option<CompositeValue>,
 
     radius: Option<CompositeValue>,
 
+    dataset_index: Option<f64>,
+
+    dataset_id: Option<String>,
+
+    series_layout_by: Option<SeriesLayoutBy>,
+
+    encode: Option<DimensionEncode>,
+
+    #[serde(flatten)]
+    animation: Animation,
+
+    tooltip: Option<Tooltip>,
+
+    zlevel: Option<f64>,
+
+    z: Option<f64>,
 
     data: DataFrame,
 }
@@ -67,7 +108,7 @@ impl Pie {
             name: None,
             color_by: None,
             legend_hover_link: None,
-            coordiate_system: None,
+            coordinate_system: None,
             geo_index: None,
             calendar_index: None,
             selected_mode: None,
@@ -75,13 +116,32 @@ impl Pie {
             clockwise: None,
             avoid_label_overlap: None,
             start_angle: None,
+            end_angle: None,
+            min_angle: None,
+            min_show_label_angle: None,
+            pad_angle: None,
+            percent_precision: None,
+            still_show_zero_sum: None,
+            show_empty_circle: None,
+            empty_circle_style: None,
             rose_type: None,
             label: None,
             label_line: None,
+            label_layout: None,
             item_style: None,
             emphasis: None,
+            blur: None,
+            select: None,
             center: None,
             radius: None,
+            dataset_index: None,
+            dataset_id: None,
+            series_layout_by: None,
+            encode: None,
+            animation: Animation::new(),
+            tooltip: None,
+            zlevel: None,
+            z: None,
             data: vec![],
         }
     }
@@ -106,11 +166,16 @@ impl Pie {
         self
     }
 
-    pub fn coordiate_system<C: Into<CoordinateSystem>>(mut self, coordiate_system: C) -> Self {
-        self.coordiate_system = Some(coordiate_system.into());
+    pub fn coordinate_system<C: Into<CoordinateSystem>>(mut self, coordinate_system: C) -> Self {
+        self.coordinate_system = Some(coordinate_system.into());
         self
     }
 
+    #[deprecated(note = "use `coordinate_system` instead")]
+    pub fn coordiate_system<C: Into<CoordinateSystem>>(self, coordinate_system: C) -> Self {
+        self.coordinate_system(coordinate_system)
+    }
+
     pub fn geo_index<F: Into<f64>>(mut self, geo_index: F) -> Self {
         self.geo_index = Some(geo_index.into());
         self
@@ -121,8 +186,8 @@ impl Pie {
         self
     }
 
-    pub fn selected_mode(mut self, selected_mode: bool) -> Self {
-        self.selected_mode = Some(selected_mode);
+    pub fn selected_mode<S: Into<SelectedMode>>(mut self, selected_mode: S) -> Self {
+        self.selected_mode = Some(selected_mode.into());
         self
     }
 
@@ -146,6 +211,54 @@ impl Pie {
         self
     }
 
+    /// End angle of the pie, defaulting to a full circle from the start
+    /// angle.
+    pub fn end_angle<F: Into<f64>>(mut self, end_angle: F) -> Self {
+        self.end_angle = Some(end_angle.into());
+        self
+    }
+
+    /// Minimum angle of a sector, so that small values remain visible.
+    pub fn min_angle<F: Into<f64>>(mut self, min_angle: F) -> Self {
+        self.min_angle = Some(min_angle.into());
+        self
+    }
+
+    /// Sectors smaller than this angle have no label.
+    pub fn min_show_label_angle<F: Into<f64>>(mut self, min_show_label_angle: F) -> Self {
+        self.min_show_label_angle = Some(min_show_label_angle.into());
+        self
+    }
+
+    /// Angle of the gap between sectors.
+    pub fn pad_angle<F: Into<f64>>(mut self, pad_angle: F) -> Self {
+        self.pad_angle = Some(pad_angle.into());
+        self
+    }
+
+    /// Number of decimal places of the percentages shown by `{d}`.
+    pub fn percent_precision<F: Into<f64>>(mut self, percent_precision: F) -> Self {
+        self.percent_precision = Some(percent_precision.into());
+        self
+    }
+
+    /// Whether to split the pie evenly when all values are zero.
+    pub fn still_show_zero_sum(mut self, still_show_zero_sum: bool) -> Self {
+        self.still_show_zero_sum = Some(still_show_zero_sum);
+        self
+    }
+
+    /// Whether to draw a placeholder circle when there is no data.
+    pub fn show_empty_circle(mut self, show_empty_circle: bool) -> Self {
+        self.show_empty_circle = Some(show_empty_circle);
+        self
+    }
+
+    pub fn empty_circle_style<I: Into<ItemStyle>>(mut self, empty_circle_style: I) -> Self {
+        self.empty_circle_style = Some(empty_circle_style.into());
+        self
+    }
+
     pub fn rose_type<P: Into<PieRoseType>>(mut self, rose_type: P) -> Self {
         self.rose_type = Some(rose_type.into());
         self
@@ -161,6 +274,11 @@ impl Pie {
         self
     }
 
+    pub fn label_layout<L: Into<LabelLayout>>(mut self, label_layout: L) -> Self {
+        self.label_layout = Some(label_layout.into());
+        self
+    }
+
     pub fn item_style<I: Into<ItemStyle>>(mut self, item_style: I) -> Self {
         self.item_style = Some(item_style.into());
         self
@@ -171,6 +289,16 @@ impl Pie {
         self
     }
 
+    pub fn blur<B: Into<Blur>>(mut self, blur: B) -> Self {
+        self.blur = Some(blur.into());
+        self
+    }
+
+    pub fn select<S: Into<Select>>(mut self, select: S) -> Self {
+        self.select = Some(select.into());
+        self
+    }
+
     pub fn center<C: Into<CompositeValue>>(mut self, center: C) -> Self {
         self.center = Some(center.into());
         self
@@ -181,8 +309,72 @@ impl Pie {
         self
     }
 
+    pub fn dataset_index<F: Into<f64>>(mut self, dataset_index: F) -> Self {
+        self.dataset_index = Some(dataset_index.into());
+        self
+    }
+
+    pub fn dataset_id<S: Into<String>>(mut self, dataset_id: S) -> Self {
+        self.dataset_id = Some(dataset_id.into());
+        self
+    }
+
+    pub fn series_layout_by<S: Into<SeriesLayoutBy>>(mut self, series_layout_by: S) -> Self {
+        self.series_layout_by = Some(series_layout_by.into());
+        self
+    }
+
+    pub fn encode<E: Into<DimensionEncode>>(mut self, encode: E) -> Self {
+        self.encode = Some(encode.into());
+        self
+    }
+
+    pub fn tooltip<T: Into<Tooltip>>(mut self, tooltip: T) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    pub fn zlevel<F: Into<f64>>(mut self, zlevel: F) -> Self {
+        self.zlevel = Some(zlevel.into());
+        self
+    }
+
+    pub fn z<F: Into<f64>>(mut self, z: F) -> Self {
+        self.z = Some(z.into());
+        self
+    }
+
     pub fn data<D: Into<DataPoint>>(mut self, data: Vec<D>) -> Self {
         self.data = data.into_iter().map(|d| d.into()).collect();
         self
     }
 }
+
+impl_animation!(Pie);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn doughnut_from_dataset() {
+        let pie = Pie::new()
+            .radius(vec!["40%", "70%"])
+            .pad_angle(2)
+            .min_angle(5)
+            .label_layout(LabelLayout::new().hide_overlap(true))
+            .dataset_index(1)
+            .encode(DimensionEncode::new().item_name("product").value("2015"));
+        assert_eq!(
+            serde_json::to_value(&pie).unwrap(),
+            serde_json::json!({
+                "minAngle": 5.0,
+                "padAngle": 2.0,
+                "labelLayout": {"hideOverlap": true},
+                "radius": ["40%", "70%"],
+                "datasetIndex": 1.0,
+                "encode": {"itemName": "product", "value": "2015"},
+            })
+        );
+    }
+}
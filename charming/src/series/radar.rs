@@ -3,7 +3,10 @@ use macros::serde_auto;
 
 use crate::{
     datatype::{DataFrame, DataPoint},
-    element::{AreaStyle, ColorBy, Emphasis, LineStyle, Symbol, Tooltip},
+    element::{
+        impl_animation, Animation, AreaStyle, Blur, ColorBy, Emphasis, ItemStyle, Label, LineStyle,
+        Select, SelectedMode, Symbol, Tooltip,
+    },
 };
 
 #[serde_auto]
@@ -14,7 +17,6 @@ pub struct Radar {
 
     color_by: Option<ColorBy>,
 
-
     data: DataFrame,
 
     id: Option<String>,
@@ -31,11 +33,30 @@ pub struct Radar {
 
     symbol_size: Option<f64>,
 
+    #[serde(flatten)]
+    animation: Animation,
+
     tooltip: Option<Tooltip>,
 
     line_style: Option<LineStyle>,
 
     emphasis: Option<Emphasis>,
+
+    label: Option<Label>,
+
+    item_style: Option<ItemStyle>,
+
+    legend_hover_link: Option<bool>,
+
+    blur: Option<Blur>,
+
+    select: Option<Select>,
+
+    selected_mode: Option<SelectedMode>,
+
+    zlevel: Option<f64>,
+
+    z: Option<f64>,
 }
 
 impl Radar {
@@ -51,12 +72,25 @@ impl Radar {
             symbol_keep_aspect: None,
             symbol_rotate: None,
             symbol_size: None,
+            animation: Animation::new(),
             tooltip: None,
             line_style: None,
             emphasis: None,
+            label: None,
+            item_style: None,
+            legend_hover_link: None,
+            blur: None,
+            select: None,
+            selected_mode: None,
+            zlevel: None,
+            z: None,
         }
     }
 
+    /// Area style of the polygons. Style individual polygons with
+    /// [`DataPointItem::area_style`].
+    ///
+    /// [`DataPointItem::area_style`]: crate::datatype::DataPointItem::area_style
     pub fn area_style<A: Into<AreaStyle>>(mut self, area_style: A) -> Self {
         self.area_style = Some(area_style.into());
         self
@@ -121,4 +155,83 @@ impl Radar {
         self.emphasis = Some(emphasis.into());
         self
     }
+
+    /// Label shown at the vertices, e.g. the value of each indicator.
+    pub fn label<L: Into<Label>>(mut self, label: L) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn item_style<I: Into<ItemStyle>>(mut self, item_style: I) -> Self {
+        self.item_style = Some(item_style.into());
+        self
+    }
+
+    pub fn legend_hover_link(mut self, legend_hover_link: bool) -> Self {
+        self.legend_hover_link = Some(legend_hover_link);
+        self
+    }
+
+    pub fn blur<B: Into<Blur>>(mut self, blur: B) -> Self {
+        self.blur = Some(blur.into());
+        self
+    }
+
+    /// Style of the selected polygons.
+    pub fn select<S: Into<Select>>(mut self, select: S) -> Self {
+        self.select = Some(select.into());
+        self
+    }
+
+    pub fn selected_mode<S: Into<SelectedMode>>(mut self, selected_mode: S) -> Self {
+        self.selected_mode = Some(selected_mode.into());
+        self
+    }
+
+    pub fn zlevel<F: Into<f64>>(mut self, zlevel: F) -> Self {
+        self.zlevel = Some(zlevel.into());
+        self
+    }
+
+    pub fn z<F: Into<f64>>(mut self, z: F) -> Self {
+        self.z = Some(z.into());
+        self
+    }
+}
+
+impl_animation!(Radar);
+
+#[cfg(test)]
+mod test {
+    use crate::{datatype::DataPointItem, element::LabelPosition};
+
+    use super::*;
+
+    #[test]
+    fn styled_radar() {
+        let radar = Radar::new()
+            .label(Label::new().show(true).position(LabelPosition::Top))
+            .selected_mode(SelectedMode::Multiple)
+            .select(Select::new().item_style(ItemStyle::new().border_width(2)))
+            .data(vec![DataPointItem::new(vec![4, 3, 5])
+                .name("Team A")
+                .symbol(Symbol::Rect)
+                .line_style(LineStyle::new().width(3))
+                .area_style(AreaStyle::new().opacity(0.2))]);
+        assert_eq!(
+            serde_json::to_value(&radar).unwrap(),
+            serde_json::json!({
+                "data": [{
+                    "value": [4, 3, 5],
+                    "name": "Team A",
+                    "symbol": "rect",
+                    "lineStyle": {"width": 3.0},
+                    "areaStyle": {"opacity": 0.2},
+                }],
+                "label": {"show": true, "position": "top"},
+                "select": {"itemStyle": {"borderWidth": 2.0}},
+                "selectedMode": "multiple",
+            })
+        );
+    }
 }
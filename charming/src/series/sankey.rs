@@ -3,7 +3,10 @@ use macros::serde_auto;
 
 use crate::{
     datatype::CompositeValue,
-    element::{Emphasis, Label, LineStyle, Orient},
+    element::{
+        impl_animation, Animation, Blur, Emphasis, ItemStyle, Label, LineStyle, Orient, Select,
+        SelectedMode, Tooltip,
+    },
 };
 
 #[serde_auto]
@@ -15,6 +18,55 @@ pub enum SankeyNodeAlign {
     Justify,
 }
 
+/// Styles applied to all the nodes and outgoing links at one depth of a
+/// sankey diagram.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SankeyLevel {
+    depth: f64,
+
+    label: Option<Label>,
+
+    item_style: Option<ItemStyle>,
+
+    line_style: Option<LineStyle>,
+
+    emphasis: Option<Emphasis>,
+}
+
+impl SankeyLevel {
+    pub fn new<F: Into<f64>>(depth: F) -> Self {
+        Self {
+            depth: depth.into(),
+            label: None,
+            item_style: None,
+            line_style: None,
+            emphasis: None,
+        }
+    }
+
+    pub fn label<L: Into<Label>>(mut self, label: L) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn item_style<I: Into<ItemStyle>>(mut self, item_style: I) -> Self {
+        self.item_style = Some(item_style.into());
+        self
+    }
+
+    pub fn line_style<L: Into<LineStyle>>(mut self, line_style: L) -> Self {
+        self.line_style = Some(line_style.into());
+        self
+    }
+
+    pub fn emphasis<E: Into<Emphasis>>(mut self, emphasis: E) -> Self {
+        self.emphasis = Some(emphasis.into());
+        self
+    }
+}
+
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -24,19 +76,60 @@ pub struct SankeyNode {
     pub value: Option<f64>,
 
     pub depth: Option<f64>,
+
+    pub label: Option<Label>,
+
+    pub item_style: Option<ItemStyle>,
+
+    pub emphasis: Option<Emphasis>,
 }
 
-impl<S> From<S> for SankeyNode
-where
-    S: Into<String>,
-{
-    fn from(name: S) -> Self {
+impl SankeyNode {
+    pub fn new<S: Into<String>>(name: S) -> Self {
         SankeyNode {
             name: name.into(),
             value: None,
             depth: None,
+            label: None,
+            item_style: None,
+            emphasis: None,
         }
     }
+
+    pub fn value<F: Into<f64>>(mut self, value: F) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Fixes the column of the node instead of deriving it from the links.
+    pub fn depth<F: Into<f64>>(mut self, depth: F) -> Self {
+        self.depth = Some(depth.into());
+        self
+    }
+
+    pub fn label<L: Into<Label>>(mut self, label: L) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn item_style<I: Into<ItemStyle>>(mut self, item_style: I) -> Self {
+        self.item_style = Some(item_style.into());
+        self
+    }
+
+    pub fn emphasis<E: Into<Emphasis>>(mut self, emphasis: E) -> Self {
+        self.emphasis = Some(emphasis.into());
+        self
+    }
+}
+
+impl<S> From<S> for SankeyNode
+where
+    S: Into<String>,
+{
+    fn from(name: S) -> Self {
+        SankeyNode::new(name)
+    }
 }
 
 #[serde_auto]
@@ -44,8 +137,46 @@ where
 #[serde(rename_all = "camelCase")]
 pub struct SankeyLink {
     pub source: String,
+
     pub target: String,
+
     pub value: f64,
+
+    pub line_style: Option<LineStyle>,
+
+    pub edge_label: Option<Label>,
+
+    pub emphasis: Option<Emphasis>,
+}
+
+impl SankeyLink {
+    pub fn new<S: Into<String>, F: Into<f64>>(source: S, target: S, value: F) -> Self {
+        SankeyLink {
+            source: source.into(),
+            target: target.into(),
+            value: value.into(),
+            line_style: None,
+            edge_label: None,
+            emphasis: None,
+        }
+    }
+
+    /// Style of this link. The color can also be `"source"`, `"target"` or
+    /// `"gradient"` to derive it from the connected nodes.
+    pub fn line_style<L: Into<LineStyle>>(mut self, line_style: L) -> Self {
+        self.line_style = Some(line_style.into());
+        self
+    }
+
+    pub fn edge_label<L: Into<Label>>(mut self, edge_label: L) -> Self {
+        self.edge_label = Some(edge_label.into());
+        self
+    }
+
+    pub fn emphasis<E: Into<Emphasis>>(mut self, emphasis: E) -> Self {
+        self.emphasis = Some(emphasis.into());
+        self
+    }
 }
 
 impl<S, F> From<(S, S, F)> for SankeyLink
@@ -54,11 +185,7 @@ where
     F: Into<f64>,
 {
     fn from((source, target, value): (S, S, F)) -> Self {
-        SankeyLink {
-            source: source.into(),
-            target: target.into(),
-            value: value.into(),
-        }
+        SankeyLink::new(source, target, value)
     }
 }
 
@@ -88,17 +215,40 @@ pub struct Sankey {
 
     emphasis: Option<Emphasis>,
 
+    blur: Option<Blur>,
+
+    select: Option<Select>,
+
+    selected_mode: Option<SelectedMode>,
+
     orient: Option<Orient>,
 
     label: Option<Label>,
 
     node_align: Option<SankeyNodeAlign>,
 
+    node_width: Option<f64>,
+
+    node_gap: Option<f64>,
+
+    layout_iterations: Option<f64>,
+
+    draggable: Option<bool>,
+
+    item_style: Option<ItemStyle>,
+
     line_style: Option<LineStyle>,
 
+    edge_label: Option<Label>,
 
-    links: Vec<SankeyLink>,
+    #[serde(flatten)]
+    animation: Animation,
+
+    tooltip: Option<Tooltip>,
 
+    levels: Vec<SankeyLevel>,
+
+    links: Vec<SankeyLink>,
 
     data: Vec<SankeyNode>,
 }
@@ -117,10 +267,22 @@ impl Sankey {
             width: None,
             height: None,
             emphasis: None,
+            blur: None,
+            select: None,
+            selected_mode: None,
             orient: None,
             label: None,
             node_align: None,
+            node_width: None,
+            node_gap: None,
+            layout_iterations: None,
+            draggable: None,
+            item_style: None,
             line_style: None,
+            edge_label: None,
+            animation: Animation::new(),
+            tooltip: None,
+            levels: vec![],
             links: vec![],
             data: vec![],
         }
@@ -181,6 +343,21 @@ impl Sankey {
         self
     }
 
+    pub fn blur<B: Into<Blur>>(mut self, blur: B) -> Self {
+        self.blur = Some(blur.into());
+        self
+    }
+
+    pub fn select<S: Into<Select>>(mut self, select: S) -> Self {
+        self.select = Some(select.into());
+        self
+    }
+
+    pub fn selected_mode<S: Into<SelectedMode>>(mut self, selected_mode: S) -> Self {
+        self.selected_mode = Some(selected_mode.into());
+        self
+    }
+
     pub fn orient<O: Into<Orient>>(mut self, orient: O) -> Self {
         self.orient = Some(orient.into());
         self
@@ -196,11 +373,58 @@ impl Sankey {
         self
     }
 
+    /// Width of the node rectangles.
+    pub fn node_width<F: Into<f64>>(mut self, node_width: F) -> Self {
+        self.node_width = Some(node_width.into());
+        self
+    }
+
+    /// Gap between the nodes of a column.
+    pub fn node_gap<F: Into<f64>>(mut self, node_gap: F) -> Self {
+        self.node_gap = Some(node_gap.into());
+        self
+    }
+
+    /// Number of iterations used to optimize the node layout. `0` keeps the
+    /// nodes in the order of the data.
+    pub fn layout_iterations<F: Into<f64>>(mut self, layout_iterations: F) -> Self {
+        self.layout_iterations = Some(layout_iterations.into());
+        self
+    }
+
+    pub fn draggable(mut self, draggable: bool) -> Self {
+        self.draggable = Some(draggable);
+        self
+    }
+
+    pub fn item_style<I: Into<ItemStyle>>(mut self, item_style: I) -> Self {
+        self.item_style = Some(item_style.into());
+        self
+    }
+
+    /// Style of the links. The color can also be `"source"`, `"target"` or
+    /// `"gradient"` to derive it from the connected nodes.
     pub fn line_style<L: Into<LineStyle>>(mut self, line_style: L) -> Self {
         self.line_style = Some(line_style.into());
         self
     }
 
+    /// Label shown on the links.
+    pub fn edge_label<L: Into<Label>>(mut self, edge_label: L) -> Self {
+        self.edge_label = Some(edge_label.into());
+        self
+    }
+
+    pub fn tooltip<T: Into<Tooltip>>(mut self, tooltip: T) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    pub fn levels(mut self, levels: Vec<SankeyLevel>) -> Self {
+        self.levels = levels;
+        self
+    }
+
     pub fn data<S: Into<SankeyNode>>(mut self, data: Vec<S>) -> Self {
         self.data = data.into_iter().map(|s| s.into()).collect();
         self
@@ -216,3 +440,48 @@ impl Sankey {
         self
     }
 }
+
+impl_animation!(Sankey);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn styled_sankey() {
+        let sankey = Sankey::new()
+            .node_width(10)
+            .node_gap(12)
+            .layout_iterations(0)
+            .levels(vec![SankeyLevel::new(0)
+                .item_style(ItemStyle::new().color("#fbb4ae"))
+                .line_style(LineStyle::new().color("source").opacity(0.6))])
+            .nodes(vec![
+                SankeyNode::new("a").depth(0),
+                SankeyNode::new("b").depth(2),
+            ])
+            .links(vec![
+                SankeyLink::new("a", "b", 5).edge_label(Label::new().show(true).formatter("{c}"))
+            ]);
+        assert_eq!(
+            serde_json::to_value(&sankey).unwrap(),
+            serde_json::json!({
+                "nodeWidth": 10.0,
+                "nodeGap": 12.0,
+                "layoutIterations": 0.0,
+                "levels": [{
+                    "depth": 0.0,
+                    "itemStyle": {"color": "#fbb4ae"},
+                    "lineStyle": {"color": "source", "opacity": 0.6},
+                }],
+                "links": [{
+                    "source": "a",
+                    "target": "b",
+                    "value": 5.0,
+                    "edgeLabel": {"show": true, "formatter": "{c}"},
+                }],
+                "data": [{"name": "a", "depth": 0.0}, {"name": "b", "depth": 2.0}],
+            })
+        );
+    }
+}
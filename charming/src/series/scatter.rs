@@ -4,8 +4,9 @@ use macros::serde_auto;
 use crate::{
     datatype::{DataFrame, DataPoint},
     element::{
-        ColorBy, CoordinateSystem, DimensionEncode, Emphasis, ItemStyle, MarkArea, MarkLine,
-        Symbol, SymbolSize,
+        impl_animation, Animation, Blur, ColorBy, CoordinateSystem, Cursor, DimensionEncode,
+        Emphasis, ItemStyle, Label, MarkArea, MarkLine, MarkPoint, Select, SelectedMode,
+        SeriesLayoutBy, Symbol, SymbolSize, Tooltip,
     },
 };
 
@@ -21,18 +22,50 @@ pub struct Scatter {
 
     dataset_index: Option<f64>,
 
+    dataset_id: Option<String>,
+
+    series_layout_by: Option<SeriesLayoutBy>,
+
     coordinate_system: Option<CoordinateSystem>,
 
     x_axis_index: Option<f64>,
 
     y_axis_index: Option<f64>,
 
+    polar_index: Option<f64>,
+
+    geo_index: Option<f64>,
+
+    calendar_index: Option<f64>,
+
+    legend_hover_link: Option<bool>,
+
     symbol: Option<Symbol>,
 
     symbol_size: Option<SymbolSize>,
 
+    symbol_rotate: Option<f64>,
+
+    symbol_keep_aspect: Option<bool>,
+
+    symbol_offset: Option<(f64, f64)>,
+
+    large: Option<bool>,
+
+    large_threshold: Option<f64>,
+
+    progressive: Option<f64>,
+
+    progressive_threshold: Option<f64>,
+
+    cursor: Option<Cursor>,
+
     encode: Option<DimensionEncode>,
 
+    label: Option<Label>,
+
+    mark_point: Option<MarkPoint>,
+
     mark_line: Option<MarkLine>,
 
     mark_area: Option<MarkArea>,
@@ -41,6 +74,22 @@ pub struct Scatter {
 
     emphasis: Option<Emphasis>,
 
+    blur: Option<Blur>,
+
+    select: Option<Select>,
+
+    selected_mode: Option<SelectedMode>,
+
+    clip: Option<bool>,
+
+    #[serde(flatten)]
+    animation: Animation,
+
+    tooltip: Option<Tooltip>,
+
+    zlevel: Option<f64>,
+
+    z: Option<f64>,
 
     data: DataFrame,
 }
@@ -52,16 +101,40 @@ impl Scatter {
             name: None,
             color_by: None,
             dataset_index: None,
+            dataset_id: None,
+            series_layout_by: None,
             coordinate_system: None,
             x_axis_index: None,
             y_axis_index: None,
+            polar_index: None,
+            geo_index: None,
+            calendar_index: None,
+            legend_hover_link: None,
             symbol: None,
             symbol_size: None,
+            symbol_rotate: None,
+            symbol_keep_aspect: None,
+            symbol_offset: None,
+            large: None,
+            large_threshold: None,
+            progressive: None,
+            progressive_threshold: None,
+            cursor: None,
             encode: None,
+            label: None,
+            mark_point: None,
             mark_line: None,
             mark_area: None,
             item_style: None,
             emphasis: None,
+            blur: None,
+            select: None,
+            selected_mode: None,
+            clip: None,
+            animation: Animation::new(),
+            tooltip: None,
+            zlevel: None,
+            z: None,
             data: vec![],
         }
     }
@@ -86,6 +159,16 @@ impl Scatter {
         self
     }
 
+    pub fn dataset_id<S: Into<String>>(mut self, dataset_id: S) -> Self {
+        self.dataset_id = Some(dataset_id.into());
+        self
+    }
+
+    pub fn series_layout_by<S: Into<SeriesLayoutBy>>(mut self, series_layout_by: S) -> Self {
+        self.series_layout_by = Some(series_layout_by.into());
+        self
+    }
+
     pub fn coordinate_system<C: Into<CoordinateSystem>>(mut self, coordinate_system: C) -> Self {
         self.coordinate_system = Some(coordinate_system.into());
         self
@@ -101,21 +184,100 @@ impl Scatter {
         self
     }
 
+    /// Index of the polar component to use with [`CoordinateSystem::Polar`].
+    pub fn polar_index<F: Into<f64>>(mut self, polar_index: F) -> Self {
+        self.polar_index = Some(polar_index.into());
+        self
+    }
+
+    /// Index of the geo component to use with [`CoordinateSystem::Geo`].
+    pub fn geo_index<F: Into<f64>>(mut self, geo_index: F) -> Self {
+        self.geo_index = Some(geo_index.into());
+        self
+    }
+
+    /// Index of the calendar component to use with
+    /// [`CoordinateSystem::Calendar`].
+    pub fn calendar_index<F: Into<f64>>(mut self, calendar_index: F) -> Self {
+        self.calendar_index = Some(calendar_index.into());
+        self
+    }
+
+    pub fn legend_hover_link(mut self, legend_hover_link: bool) -> Self {
+        self.legend_hover_link = Some(legend_hover_link);
+        self
+    }
+
     pub fn symbol(mut self, symbol: Symbol) -> Self {
         self.symbol = Some(symbol);
         self
     }
 
+    /// Size of the symbols, either constant or computed per data item, see
+    /// [`SymbolSize::dimension`].
     pub fn symbol_size<S: Into<SymbolSize>>(mut self, symbol_size: S) -> Self {
         self.symbol_size = Some(symbol_size.into());
         self
     }
 
+    pub fn symbol_rotate<F: Into<f64>>(mut self, symbol_rotate: F) -> Self {
+        self.symbol_rotate = Some(symbol_rotate.into());
+        self
+    }
+
+    pub fn symbol_keep_aspect(mut self, symbol_keep_aspect: bool) -> Self {
+        self.symbol_keep_aspect = Some(symbol_keep_aspect);
+        self
+    }
+
+    pub fn symbol_offset<F: Into<f64>>(mut self, symbol_offset: (F, F)) -> Self {
+        self.symbol_offset = Some((symbol_offset.0.into(), symbol_offset.1.into()));
+        self
+    }
+
+    /// Whether to enable the optimization of large-scale data.
+    pub fn large(mut self, large: bool) -> Self {
+        self.large = Some(large);
+        self
+    }
+
+    /// The number of data items above which the large mode is used.
+    pub fn large_threshold<F: Into<f64>>(mut self, large_threshold: F) -> Self {
+        self.large_threshold = Some(large_threshold.into());
+        self
+    }
+
+    pub fn progressive<F: Into<f64>>(mut self, progressive: F) -> Self {
+        self.progressive = Some(progressive.into());
+        self
+    }
+
+    pub fn progressive_threshold<F: Into<f64>>(mut self, progressive_threshold: F) -> Self {
+        self.progressive_threshold = Some(progressive_threshold.into());
+        self
+    }
+
+    /// Mouse cursor shown when hovering the symbols.
+    pub fn cursor<C: Into<Cursor>>(mut self, cursor: C) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+
     pub fn encode<D: Into<DimensionEncode>>(mut self, encode: D) -> Self {
         self.encode = Some(encode.into());
         self
     }
 
+    pub fn label<L: Into<Label>>(mut self, label: L) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn mark_point<M: Into<MarkPoint>>(mut self, mark_point: M) -> Self {
+        self.mark_point = Some(mark_point.into());
+        self
+    }
+
     pub fn mark_line<M: Into<MarkLine>>(mut self, mark_line: M) -> Self {
         self.mark_line = Some(mark_line.into());
         self
@@ -136,8 +298,67 @@ impl Scatter {
         self
     }
 
+    pub fn blur<B: Into<Blur>>(mut self, blur: B) -> Self {
+        self.blur = Some(blur.into());
+        self
+    }
+
+    pub fn select<S: Into<Select>>(mut self, select: S) -> Self {
+        self.select = Some(select.into());
+        self
+    }
+
+    pub fn selected_mode<S: Into<SelectedMode>>(mut self, selected_mode: S) -> Self {
+        self.selected_mode = Some(selected_mode.into());
+        self
+    }
+
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = Some(clip);
+        self
+    }
+
+    pub fn tooltip<T: Into<Tooltip>>(mut self, tooltip: T) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    pub fn zlevel<F: Into<f64>>(mut self, zlevel: F) -> Self {
+        self.zlevel = Some(zlevel.into());
+        self
+    }
+
+    pub fn z<F: Into<f64>>(mut self, z: F) -> Self {
+        self.z = Some(z.into());
+        self
+    }
+
     pub fn data<D: Into<DataPoint>>(mut self, data: Vec<D>) -> Self {
         self.data = data.into_iter().map(|d| d.into()).collect();
         self
     }
 }
+
+impl_animation!(Scatter);
+
+#[cfg(test)]
+mod test {
+    use crate::element::process_raw_strings;
+
+    use super::*;
+
+    #[test]
+    fn bubble_scatter() {
+        let scatter = Scatter::new()
+            .coordinate_system(CoordinateSystem::Geo)
+            .geo_index(0)
+            .symbol_size(SymbolSize::dimension(2))
+            .large(true)
+            .large_threshold(2000)
+            .data(vec![vec![121.4, 31.2, 12.0]]);
+        assert_eq!(
+            process_raw_strings(&serde_json::to_string(&scatter).unwrap()),
+            r#"{"coordinateSystem":"geo","geoIndex":0.0,"symbolSize":function (value) { return value[2]; },"large":true,"largeThreshold":2000.0,"data":[[121.4,31.2,12.0]]}"#
+        );
+    }
+}
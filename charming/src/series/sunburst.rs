@@ -1,7 +1,34 @@
 use serde::{Deserialize, Serialize};
 use macros::serde_auto;
 
-use crate::element::{Emphasis, ItemStyle, Label, Sort};
+use crate::element::{
+    impl_animation, Animation, Blur, Emphasis, ItemStyle, Label, Select, SelectedMode, Sort,
+    Tooltip,
+};
+
+use super::hierarchy::{nest_paths, PathNode};
+
+/// What happens when a sunburst node is clicked.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub enum SunburstNodeClick {
+    /// Do nothing.
+    None,
+    /// Make the clicked node the root of the chart.
+    RootToNode,
+    /// Open the `link` of the clicked node.
+    Link,
+}
+
+impl Serialize for SunburstNodeClick {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            SunburstNodeClick::None => serializer.serialize_bool(false),
+            SunburstNodeClick::RootToNode => serializer.serialize_str("rootToNode"),
+            SunburstNodeClick::Link => serializer.serialize_str("link"),
+        }
+    }
+}
 
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -51,13 +78,26 @@ impl SunburstLevel {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SunburstNode {
+    id: Option<String>,
+
     name: String,
 
     value: Option<f64>,
 
+    link: Option<String>,
+
+    target: Option<String>,
+
     #[serde(skip_deserializing)]
     item_style: Option<ItemStyle>,
 
+    label: Option<Label>,
+
+    emphasis: Option<Emphasis>,
+
+    blur: Option<Blur>,
+
+    select: Option<Select>,
 
     children: Vec<SunburstNode>,
 }
@@ -65,25 +105,96 @@ pub struct SunburstNode {
 impl SunburstNode {
     pub fn new<S: Into<String>>(name: S) -> Self {
         Self {
+            id: None,
             name: name.into(),
             value: None,
+            link: None,
+            target: None,
             item_style: None,
+            label: None,
+            emphasis: None,
+            blur: None,
+            select: None,
             children: vec![],
         }
     }
 
-    pub fn value(mut self, value: f64) -> Self {
-        self.value = Some(value);
+    /// Builds nested nodes from `(path, value)` pairs, e.g.
+    /// `("src/lib.rs".split('/'), 12.0)`. Paths sharing a prefix share its
+    /// nodes, and each value is set on the last node of its path. Values of a
+    /// repeated path are summed, and empty paths are ignored.
+    pub fn from_paths<P, S, F>(paths: Vec<(P, F)>) -> Vec<SunburstNode>
+    where
+        P: IntoIterator<Item = S>,
+        S: Into<String>,
+        F: Into<f64>,
+    {
+        nest_paths(paths)
+            .into_iter()
+            .map(Self::from_path_node)
+            .collect()
+    }
+
+    fn from_path_node(node: PathNode) -> Self {
+        let mut sunburst_node = Self::new(node.name).children(
+            node.children
+                .into_iter()
+                .map(Self::from_path_node)
+                .collect(),
+        );
+        sunburst_node.value = node.value;
+        sunburst_node
+    }
+
+    pub fn id<S: Into<String>>(mut self, id: S) -> Self {
+        self.id = Some(id.into());
         self
     }
 
-    pub fn item_style(mut self, item_style: ItemStyle) -> Self {
-        self.item_style = Some(item_style);
+    pub fn value<F: Into<f64>>(mut self, value: F) -> Self {
+        self.value = Some(value.into());
         self
     }
 
-    pub fn children(mut self, children: Vec<SunburstNode>) -> Self {
-        self.children = children;
+    /// Hyperlink opened when the node is clicked with
+    /// [`SunburstNodeClick::Link`].
+    pub fn link<S: Into<String>>(mut self, link: S) -> Self {
+        self.link = Some(link.into());
+        self
+    }
+
+    pub fn target<S: Into<String>>(mut self, target: S) -> Self {
+        self.target = Some(target.into());
+        self
+    }
+
+    pub fn item_style<I: Into<ItemStyle>>(mut self, item_style: I) -> Self {
+        self.item_style = Some(item_style.into());
+        self
+    }
+
+    pub fn label<L: Into<Label>>(mut self, label: L) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn emphasis<E: Into<Emphasis>>(mut self, emphasis: E) -> Self {
+        self.emphasis = Some(emphasis.into());
+        self
+    }
+
+    pub fn blur<B: Into<Blur>>(mut self, blur: B) -> Self {
+        self.blur = Some(blur.into());
+        self
+    }
+
+    pub fn select<S: Into<Select>>(mut self, select: S) -> Self {
+        self.select = Some(select.into());
+        self
+    }
+
+    pub fn children<N: Into<SunburstNode>>(mut self, children: Vec<N>) -> Self {
+        self.children = children.into_iter().map(|n| n.into()).collect();
         self
     }
 }
@@ -124,13 +235,36 @@ pub struct Sunburst {
 
     radius: Option<(String, String)>,
 
+    node_click: Option<SunburstNodeClick>,
+
+    render_label_for_zero_data: Option<bool>,
+
+    start_angle: Option<f64>,
+
+    min_angle: Option<f64>,
+
+    clockwise: Option<bool>,
+
+    item_style: Option<ItemStyle>,
+
+    label: Option<Label>,
+
     emphasis: Option<Emphasis>,
 
-    sort: Option<Sort>,
+    blur: Option<Blur>,
+
+    select: Option<Select>,
+
+    selected_mode: Option<SelectedMode>,
 
+    sort: Option<Sort>,
 
     levels: Vec<SunburstLevel>,
 
+    #[serde(flatten)]
+    animation: Animation,
+
+    tooltip: Option<Tooltip>,
 
     data: Vec<SunburstNode>,
 }
@@ -144,9 +278,21 @@ impl Sunburst {
             z: None,
             center: None,
             radius: None,
+            node_click: None,
+            render_label_for_zero_data: None,
+            start_angle: None,
+            min_angle: None,
+            clockwise: None,
+            item_style: None,
+            label: None,
             emphasis: None,
+            blur: None,
+            select: None,
+            selected_mode: None,
             sort: None,
             levels: vec![],
+            animation: Animation::new(),
+            tooltip: None,
             data: vec![],
         }
     }
@@ -181,11 +327,63 @@ impl Sunburst {
         self
     }
 
+    pub fn node_click<N: Into<SunburstNodeClick>>(mut self, node_click: N) -> Self {
+        self.node_click = Some(node_click.into());
+        self
+    }
+
+    /// Whether to show labels of nodes whose value is zero.
+    pub fn render_label_for_zero_data(mut self, render_label_for_zero_data: bool) -> Self {
+        self.render_label_for_zero_data = Some(render_label_for_zero_data);
+        self
+    }
+
+    pub fn start_angle<F: Into<f64>>(mut self, start_angle: F) -> Self {
+        self.start_angle = Some(start_angle.into());
+        self
+    }
+
+    /// Minimum angle of a node, so that nodes with small values stay clickable.
+    pub fn min_angle<F: Into<f64>>(mut self, min_angle: F) -> Self {
+        self.min_angle = Some(min_angle.into());
+        self
+    }
+
+    pub fn clockwise(mut self, clockwise: bool) -> Self {
+        self.clockwise = Some(clockwise);
+        self
+    }
+
+    pub fn item_style<I: Into<ItemStyle>>(mut self, item_style: I) -> Self {
+        self.item_style = Some(item_style.into());
+        self
+    }
+
+    pub fn label<L: Into<Label>>(mut self, label: L) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
     pub fn emphasis(mut self, emphasis: Emphasis) -> Self {
         self.emphasis = Some(emphasis);
         self
     }
 
+    pub fn blur<B: Into<Blur>>(mut self, blur: B) -> Self {
+        self.blur = Some(blur.into());
+        self
+    }
+
+    pub fn select<S: Into<Select>>(mut self, select: S) -> Self {
+        self.select = Some(select.into());
+        self
+    }
+
+    pub fn selected_mode<S: Into<SelectedMode>>(mut self, selected_mode: S) -> Self {
+        self.selected_mode = Some(selected_mode.into());
+        self
+    }
+
     pub fn sort(mut self, sort: Sort) -> Self {
         self.sort = Some(sort);
         self
@@ -196,8 +394,49 @@ impl Sunburst {
         self
     }
 
-    pub fn data(mut self, data: Vec<SunburstNode>) -> Self {
-        self.data = data;
+    pub fn tooltip<T: Into<Tooltip>>(mut self, tooltip: T) -> Self {
+        self.tooltip = Some(tooltip.into());
         self
     }
+
+    pub fn data<N: Into<SunburstNode>>(mut self, data: Vec<N>) -> Self {
+        self.data = data.into_iter().map(|n| n.into()).collect();
+        self
+    }
+}
+
+impl_animation!(Sunburst);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sunburst_from_paths() {
+        let sunburst = Sunburst::new()
+            .node_click(SunburstNodeClick::None)
+            .min_angle(2)
+            .data(SunburstNode::from_paths(vec![
+                (vec!["src", "lib.rs"], 12.0),
+                (vec!["src", "main.rs"], 3.0),
+                (vec!["README.md"], 1.0),
+            ]));
+        assert_eq!(
+            serde_json::to_value(&sunburst).unwrap(),
+            serde_json::json!({
+                "nodeClick": false,
+                "minAngle": 2.0,
+                "data": [
+                    {
+                        "name": "src",
+                        "children": [
+                            { "name": "lib.rs", "value": 12.0 },
+                            { "name": "main.rs", "value": 3.0 },
+                        ],
+                    },
+                    { "name": "README.md", "value": 1.0 },
+                ],
+            })
+        );
+    }
 }
@@ -1,9 +1,12 @@
-use serde::{ser::SerializeSeq, Deserialize, Serialize};
 use macros::serde_auto;
+use serde::{ser::SerializeSeq, Deserialize, Serialize};
 
 use crate::{
     datatype::CompositeValue,
-    element::{BoundaryGap, ColorBy, CoordinateSystem, Label},
+    element::{
+        impl_animation, Animation, Blur, BoundaryGap, ColorBy, CoordinateSystem, Emphasis, Label,
+        Select, SelectedMode, Tooltip,
+    },
 };
 
 #[serde_auto]
@@ -78,6 +81,18 @@ pub struct ThemeRiver {
 
     label: Option<Label>,
 
+    emphasis: Option<Emphasis>,
+
+    blur: Option<Blur>,
+
+    select: Option<Select>,
+
+    selected_mode: Option<SelectedMode>,
+
+    #[serde(flatten)]
+    animation: Animation,
+
+    tooltip: Option<Tooltip>,
 
     data: Vec<ThemeRiverData>,
 }
@@ -97,6 +112,12 @@ impl ThemeRiver {
             coordinate_system: None,
             boundary_gap: None,
             label: None,
+            emphasis: None,
+            blur: None,
+            select: None,
+            selected_mode: None,
+            animation: Animation::new(),
+            tooltip: None,
             data: vec![],
         }
     }
@@ -161,8 +182,35 @@ impl ThemeRiver {
         self
     }
 
+    pub fn emphasis<E: Into<Emphasis>>(mut self, emphasis: E) -> Self {
+        self.emphasis = Some(emphasis.into());
+        self
+    }
+
+    pub fn blur<B: Into<Blur>>(mut self, blur: B) -> Self {
+        self.blur = Some(blur.into());
+        self
+    }
+
+    pub fn select<S: Into<Select>>(mut self, select: S) -> Self {
+        self.select = Some(select.into());
+        self
+    }
+
+    pub fn selected_mode<S: Into<SelectedMode>>(mut self, selected_mode: S) -> Self {
+        self.selected_mode = Some(selected_mode.into());
+        self
+    }
+
+    pub fn tooltip<T: Into<Tooltip>>(mut self, tooltip: T) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
     pub fn data<T: Into<ThemeRiverData>>(mut self, data: Vec<T>) -> Self {
         self.data = data.into_iter().map(|d| d.into()).collect();
         self
     }
 }
+
+impl_animation!(ThemeRiver);
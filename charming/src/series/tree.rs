@@ -3,9 +3,14 @@ use macros::serde_auto;
 
 use crate::{
     datatype::CompositeValue,
-    element::{Blur, Emphasis, ItemStyle, Label, Select, Symbol},
+    element::{
+        impl_animation, Animation, Blur, Emphasis, ItemStyle, Label, LineStyle, ScaleLimit, Select,
+        SelectedMode, Symbol, Tooltip,
+    },
 };
 
+use super::hierarchy::{nest_paths, PathNode};
+
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -40,17 +45,35 @@ pub enum TreeEdgeShape {
 #[serde(rename_all = "camelCase")]
 pub struct TreeLeaves {
     label: Option<Label>,
+
+    item_style: Option<ItemStyle>,
+
+    emphasis: Option<Emphasis>,
 }
 
 impl TreeLeaves {
     pub fn new() -> Self {
-        Self { label: None }
+        Self {
+            label: None,
+            item_style: None,
+            emphasis: None,
+        }
     }
 
     pub fn label(mut self, label: Label) -> Self {
         self.label = Some(label);
         self
     }
+
+    pub fn item_style<I: Into<ItemStyle>>(mut self, item_style: I) -> Self {
+        self.item_style = Some(item_style.into());
+        self
+    }
+
+    pub fn emphasis<E: Into<Emphasis>>(mut self, emphasis: E) -> Self {
+        self.emphasis = Some(emphasis.into());
+        self
+    }
 }
 
 #[serde_auto]
@@ -61,11 +84,153 @@ pub struct TreeNode {
 
     pub value: Option<f64>,
 
+    /// Whether the children of this node start out hidden.
     pub collapsed: Option<bool>,
 
+    pub symbol: Option<Symbol>,
+
+    pub symbol_size: Option<f64>,
+
+    pub item_style: Option<ItemStyle>,
+
+    pub line_style: Option<LineStyle>,
+
+    pub label: Option<Label>,
+
+    pub emphasis: Option<Emphasis>,
+
+    pub blur: Option<Blur>,
+
+    pub select: Option<Select>,
+
+    pub tooltip: Option<Tooltip>,
+
     pub children: Option<Vec<TreeNode>>,
 }
 
+impl TreeNode {
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        Self {
+            name: name.into(),
+            value: None,
+            collapsed: None,
+            symbol: None,
+            symbol_size: None,
+            item_style: None,
+            line_style: None,
+            label: None,
+            emphasis: None,
+            blur: None,
+            select: None,
+            tooltip: None,
+            children: None,
+        }
+    }
+
+    /// Builds nested nodes from `(path, value)` pairs, e.g.
+    /// `("src/lib.rs".split('/'), 12.0)`. Paths sharing a prefix share its
+    /// nodes, and each value is set on the last node of its path. Values of a
+    /// repeated path are summed, and empty paths are ignored.
+    pub fn from_paths<P, S, F>(paths: Vec<(P, F)>) -> Vec<TreeNode>
+    where
+        P: IntoIterator<Item = S>,
+        S: Into<String>,
+        F: Into<f64>,
+    {
+        nest_paths(paths)
+            .into_iter()
+            .map(Self::from_path_node)
+            .collect()
+    }
+
+    fn from_path_node(node: PathNode) -> Self {
+        let mut tree_node = Self::new(node.name);
+        tree_node.value = node.value;
+        if !node.children.is_empty() {
+            tree_node.children = Some(
+                node.children
+                    .into_iter()
+                    .map(Self::from_path_node)
+                    .collect(),
+            );
+        }
+        tree_node
+    }
+
+    pub fn value<F: Into<f64>>(mut self, value: F) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    pub fn collapsed(mut self, collapsed: bool) -> Self {
+        self.collapsed = Some(collapsed);
+        self
+    }
+
+    pub fn symbol<S: Into<Symbol>>(mut self, symbol: S) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+
+    pub fn symbol_size<F: Into<f64>>(mut self, symbol_size: F) -> Self {
+        self.symbol_size = Some(symbol_size.into());
+        self
+    }
+
+    pub fn item_style<I: Into<ItemStyle>>(mut self, item_style: I) -> Self {
+        self.item_style = Some(item_style.into());
+        self
+    }
+
+    /// Style of the edge leading to this node.
+    pub fn line_style<L: Into<LineStyle>>(mut self, line_style: L) -> Self {
+        self.line_style = Some(line_style.into());
+        self
+    }
+
+    pub fn label<L: Into<Label>>(mut self, label: L) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn emphasis<E: Into<Emphasis>>(mut self, emphasis: E) -> Self {
+        self.emphasis = Some(emphasis.into());
+        self
+    }
+
+    pub fn blur<B: Into<Blur>>(mut self, blur: B) -> Self {
+        self.blur = Some(blur.into());
+        self
+    }
+
+    pub fn select<S: Into<Select>>(mut self, select: S) -> Self {
+        self.select = Some(select.into());
+        self
+    }
+
+    pub fn tooltip<T: Into<Tooltip>>(mut self, tooltip: T) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    pub fn children<N: Into<TreeNode>>(mut self, children: Vec<N>) -> Self {
+        self.children = Some(children.into_iter().map(|n| n.into()).collect());
+        self
+    }
+}
+
+impl From<&str> for TreeNode {
+    fn from(name: &str) -> Self {
+        Self::new(name)
+    }
+}
+
+impl From<(&str, f64)> for TreeNode {
+    fn from((name, value): (&str, f64)) -> Self {
+        Self::new(name).value(value)
+    }
+}
+
 /// The tree diagram is mainly used to display the tree data structure.
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -119,10 +284,14 @@ pub struct Tree {
 
     roam: Option<bool>,
 
+    scale_limit: Option<ScaleLimit>,
+
     initial_tree_depth: Option<f64>,
 
     item_style: Option<ItemStyle>,
 
+    line_style: Option<LineStyle>,
+
     label: Option<Label>,
 
     emphasis: Option<Emphasis>,
@@ -131,16 +300,16 @@ pub struct Tree {
 
     select: Option<Select>,
 
-    selected_mode: Option<bool>,
+    selected_mode: Option<SelectedMode>,
 
     expand_and_collapse: Option<bool>,
 
-    animation_duration: Option<f64>,
-
-    animation_duration_update: Option<f64>,
-
     leaves: Option<TreeLeaves>,
 
+    #[serde(flatten)]
+    animation: Animation,
+
+    tooltip: Option<Tooltip>,
 
     data: Vec<TreeNode>,
 }
@@ -170,17 +339,19 @@ impl Tree {
             edge_shape: None,
             edge_fork_position: None,
             roam: None,
+            scale_limit: None,
             initial_tree_depth: None,
             item_style: None,
+            line_style: None,
             label: None,
             emphasis: None,
             blur: None,
             select: None,
             selected_mode: None,
             expand_and_collapse: None,
-            animation_duration: None,
-            animation_duration_update: None,
             leaves: None,
+            animation: Animation::new(),
+            tooltip: None,
             data: vec![],
         }
     }
@@ -295,6 +466,11 @@ impl Tree {
         self
     }
 
+    pub fn scale_limit<S: Into<ScaleLimit>>(mut self, scale_limit: S) -> Self {
+        self.scale_limit = Some(scale_limit.into());
+        self
+    }
+
     pub fn initial_tree_depth<F: Into<f64>>(mut self, initial_tree_depth: F) -> Self {
         self.initial_tree_depth = Some(initial_tree_depth.into());
         self
@@ -305,6 +481,12 @@ impl Tree {
         self
     }
 
+    /// Style of the edges between nodes.
+    pub fn line_style<L: Into<LineStyle>>(mut self, line_style: L) -> Self {
+        self.line_style = Some(line_style.into());
+        self
+    }
+
     pub fn label<L: Into<Label>>(mut self, label: L) -> Self {
         self.label = Some(label.into());
         self
@@ -325,8 +507,8 @@ impl Tree {
         self
     }
 
-    pub fn selected_mode(mut self, selected_mode: bool) -> Self {
-        self.selected_mode = Some(selected_mode);
+    pub fn selected_mode<S: Into<SelectedMode>>(mut self, selected_mode: S) -> Self {
+        self.selected_mode = Some(selected_mode.into());
         self
     }
 
@@ -335,18 +517,13 @@ impl Tree {
         self
     }
 
-    pub fn animation_duration<F: Into<f64>>(mut self, animation_duration: F) -> Self {
-        self.animation_duration = Some(animation_duration.into());
-        self
-    }
-
-    pub fn animation_duration_update<F: Into<f64>>(mut self, animation_duration_update: F) -> Self {
-        self.animation_duration_update = Some(animation_duration_update.into());
+    pub fn leaves<T: Into<TreeLeaves>>(mut self, leaves: T) -> Self {
+        self.leaves = Some(leaves.into());
         self
     }
 
-    pub fn leaves<T: Into<TreeLeaves>>(mut self, leaves: T) -> Self {
-        self.leaves = Some(leaves.into());
+    pub fn tooltip<T: Into<Tooltip>>(mut self, tooltip: T) -> Self {
+        self.tooltip = Some(tooltip.into());
         self
     }
 
@@ -355,3 +532,41 @@ impl Tree {
         self
     }
 }
+
+impl_animation!(Tree);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn tree_from_paths() {
+        let mut nodes = TreeNode::from_paths(vec![
+            ("eng/platform/alice".split('/'), 1.0),
+            ("eng/platform/bob".split('/'), 1.0),
+            ("eng/web".split('/'), 2.0),
+        ]);
+        nodes[0] = nodes[0]
+            .clone()
+            .collapsed(false)
+            .label(Label::new().show(true));
+        assert_eq!(
+            serde_json::to_value(Tree::new().data(nodes)).unwrap()["data"],
+            serde_json::json!([{
+                "name": "eng",
+                "collapsed": false,
+                "label": { "show": true },
+                "children": [
+                    {
+                        "name": "platform",
+                        "children": [
+                            { "name": "alice", "value": 1.0 },
+                            { "name": "bob", "value": 1.0 },
+                        ],
+                    },
+                    { "name": "web", "value": 2.0 },
+                ],
+            }])
+        );
+    }
+}
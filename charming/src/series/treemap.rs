@@ -3,9 +3,395 @@ use macros::serde_auto;
 
 use crate::{
     datatype::CompositeValue,
-    element::{Emphasis, ItemStyle, Label},
+    element::{
+        impl_animation, Animation, Blur, Color, Emphasis, ItemStyle, Label, Select, SelectedMode,
+        Sort, Tooltip,
+    },
 };
 
+/// What happens when a treemap node is clicked.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub enum TreemapNodeClick {
+    /// Do nothing.
+    None,
+    /// Zoom to the clicked node.
+    ZoomToNode,
+    /// Open the `link` of the clicked node.
+    Link,
+}
+
+impl Serialize for TreemapNodeClick {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            TreemapNodeClick::None => serializer.serialize_bool(false),
+            TreemapNodeClick::ZoomToNode => serializer.serialize_str("zoomToNode"),
+            TreemapNodeClick::Link => serializer.serialize_str("link"),
+        }
+    }
+}
+
+/// How colors of a level are assigned to its nodes.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TreemapColorMappingBy {
+    /// Map the node value onto the color list.
+    Value,
+    /// Pick colors from the list in node order.
+    Index,
+    /// Pick colors by node id, so colors stay stable across updates.
+    Id,
+}
+
+/// The breadcrumb showing the path to the current treemap root.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TreemapBreadcrumb {
+    show: Option<bool>,
+
+    left: Option<CompositeValue>,
+
+    top: Option<CompositeValue>,
+
+    right: Option<CompositeValue>,
+
+    bottom: Option<CompositeValue>,
+
+    height: Option<f64>,
+
+    empty_item_width: Option<f64>,
+
+    item_style: Option<ItemStyle>,
+
+    emphasis: Option<Emphasis>,
+}
+
+impl TreemapBreadcrumb {
+    pub fn new() -> Self {
+        Self {
+            show: None,
+            left: None,
+            top: None,
+            right: None,
+            bottom: None,
+            height: None,
+            empty_item_width: None,
+            item_style: None,
+            emphasis: None,
+        }
+    }
+
+    pub fn show(mut self, show: bool) -> Self {
+        self.show = Some(show);
+        self
+    }
+
+    pub fn left<C: Into<CompositeValue>>(mut self, left: C) -> Self {
+        self.left = Some(left.into());
+        self
+    }
+
+    pub fn top<C: Into<CompositeValue>>(mut self, top: C) -> Self {
+        self.top = Some(top.into());
+        self
+    }
+
+    pub fn right<C: Into<CompositeValue>>(mut self, right: C) -> Self {
+        self.right = Some(right.into());
+        self
+    }
+
+    pub fn bottom<C: Into<CompositeValue>>(mut self, bottom: C) -> Self {
+        self.bottom = Some(bottom.into());
+        self
+    }
+
+    pub fn height<F: Into<f64>>(mut self, height: F) -> Self {
+        self.height = Some(height.into());
+        self
+    }
+
+    /// Width of the breadcrumb items when they have no text.
+    pub fn empty_item_width<F: Into<f64>>(mut self, empty_item_width: F) -> Self {
+        self.empty_item_width = Some(empty_item_width.into());
+        self
+    }
+
+    pub fn item_style<I: Into<ItemStyle>>(mut self, item_style: I) -> Self {
+        self.item_style = Some(item_style.into());
+        self
+    }
+
+    pub fn emphasis<E: Into<Emphasis>>(mut self, emphasis: E) -> Self {
+        self.emphasis = Some(emphasis.into());
+        self
+    }
+}
+
+/// Styles applied to all the nodes at one depth of a treemap. The first
+/// level applies to the root.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TreemapLevel {
+    visual_dimension: Option<f64>,
+
+    visual_min: Option<f64>,
+
+    visual_max: Option<f64>,
+
+    color: Vec<Color>,
+
+    color_alpha: Option<(f64, f64)>,
+
+    color_saturation: Option<(f64, f64)>,
+
+    color_mapping_by: Option<TreemapColorMappingBy>,
+
+    visible_min: Option<f64>,
+
+    children_visible_min: Option<f64>,
+
+    label: Option<Label>,
+
+    upper_label: Option<Label>,
+
+    item_style: Option<ItemStyle>,
+
+    emphasis: Option<Emphasis>,
+}
+
+impl TreemapLevel {
+    pub fn new() -> Self {
+        Self {
+            visual_dimension: None,
+            visual_min: None,
+            visual_max: None,
+            color: vec![],
+            color_alpha: None,
+            color_saturation: None,
+            color_mapping_by: None,
+            visible_min: None,
+            children_visible_min: None,
+            label: None,
+            upper_label: None,
+            item_style: None,
+            emphasis: None,
+        }
+    }
+
+    /// Index of the value dimension used for visual mapping, when node
+    /// values are arrays.
+    pub fn visual_dimension<F: Into<f64>>(mut self, visual_dimension: F) -> Self {
+        self.visual_dimension = Some(visual_dimension.into());
+        self
+    }
+
+    pub fn visual_min<F: Into<f64>>(mut self, visual_min: F) -> Self {
+        self.visual_min = Some(visual_min.into());
+        self
+    }
+
+    pub fn visual_max<F: Into<f64>>(mut self, visual_max: F) -> Self {
+        self.visual_max = Some(visual_max.into());
+        self
+    }
+
+    pub fn color<C: Into<Color>>(mut self, color: Vec<C>) -> Self {
+        self.color = color.into_iter().map(|c| c.into()).collect();
+        self
+    }
+
+    pub fn color_alpha<F: Into<f64>>(mut self, color_alpha: (F, F)) -> Self {
+        self.color_alpha = Some((color_alpha.0.into(), color_alpha.1.into()));
+        self
+    }
+
+    pub fn color_saturation<F: Into<f64>>(mut self, color_saturation: (F, F)) -> Self {
+        self.color_saturation = Some((color_saturation.0.into(), color_saturation.1.into()));
+        self
+    }
+
+    pub fn color_mapping_by<C: Into<TreemapColorMappingBy>>(mut self, color_mapping_by: C) -> Self {
+        self.color_mapping_by = Some(color_mapping_by.into());
+        self
+    }
+
+    /// Nodes with an area smaller than this, in square pixels, are hidden.
+    pub fn visible_min<F: Into<f64>>(mut self, visible_min: F) -> Self {
+        self.visible_min = Some(visible_min.into());
+        self
+    }
+
+    /// Children are hidden when the node area is smaller than this, in
+    /// square pixels.
+    pub fn children_visible_min<F: Into<f64>>(mut self, children_visible_min: F) -> Self {
+        self.children_visible_min = Some(children_visible_min.into());
+        self
+    }
+
+    pub fn label<L: Into<Label>>(mut self, label: L) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Label shown in the header of nodes that have children.
+    pub fn upper_label<L: Into<Label>>(mut self, upper_label: L) -> Self {
+        self.upper_label = Some(upper_label.into());
+        self
+    }
+
+    pub fn item_style<I: Into<ItemStyle>>(mut self, item_style: I) -> Self {
+        self.item_style = Some(item_style.into());
+        self
+    }
+
+    pub fn emphasis<E: Into<Emphasis>>(mut self, emphasis: E) -> Self {
+        self.emphasis = Some(emphasis.into());
+        self
+    }
+}
+
+/// A node of the treemap hierarchy. The value of a parent node defaults to
+/// the sum of its children.
+#[serde_auto]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TreemapNode {
+    id: Option<String>,
+
+    name: Option<String>,
+
+    value: Option<CompositeValue>,
+
+    link: Option<String>,
+
+    target: Option<String>,
+
+    color: Vec<Color>,
+
+    color_mapping_by: Option<TreemapColorMappingBy>,
+
+    visible_min: Option<f64>,
+
+    children_visible_min: Option<f64>,
+
+    label: Option<Label>,
+
+    upper_label: Option<Label>,
+
+    item_style: Option<ItemStyle>,
+
+    emphasis: Option<Emphasis>,
+
+    children: Vec<TreemapNode>,
+}
+
+impl TreemapNode {
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        Self {
+            id: None,
+            name: Some(name.into()),
+            value: None,
+            link: None,
+            target: None,
+            color: vec![],
+            color_mapping_by: None,
+            visible_min: None,
+            children_visible_min: None,
+            label: None,
+            upper_label: None,
+            item_style: None,
+            emphasis: None,
+            children: vec![],
+        }
+    }
+
+    pub fn id<S: Into<String>>(mut self, id: S) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Value of the node. Use an array together with
+    /// [`TreemapLevel::visual_dimension`] to color nodes by another
+    /// dimension than their area.
+    pub fn value<C: Into<CompositeValue>>(mut self, value: C) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Hyperlink opened when the node is clicked with
+    /// [`TreemapNodeClick::Link`].
+    pub fn link<S: Into<String>>(mut self, link: S) -> Self {
+        self.link = Some(link.into());
+        self
+    }
+
+    pub fn target<S: Into<String>>(mut self, target: S) -> Self {
+        self.target = Some(target.into());
+        self
+    }
+
+    pub fn color<C: Into<Color>>(mut self, color: Vec<C>) -> Self {
+        self.color = color.into_iter().map(|c| c.into()).collect();
+        self
+    }
+
+    pub fn color_mapping_by<C: Into<TreemapColorMappingBy>>(mut self, color_mapping_by: C) -> Self {
+        self.color_mapping_by = Some(color_mapping_by.into());
+        self
+    }
+
+    pub fn visible_min<F: Into<f64>>(mut self, visible_min: F) -> Self {
+        self.visible_min = Some(visible_min.into());
+        self
+    }
+
+    pub fn children_visible_min<F: Into<f64>>(mut self, children_visible_min: F) -> Self {
+        self.children_visible_min = Some(children_visible_min.into());
+        self
+    }
+
+    pub fn label<L: Into<Label>>(mut self, label: L) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn upper_label<L: Into<Label>>(mut self, upper_label: L) -> Self {
+        self.upper_label = Some(upper_label.into());
+        self
+    }
+
+    pub fn item_style<I: Into<ItemStyle>>(mut self, item_style: I) -> Self {
+        self.item_style = Some(item_style.into());
+        self
+    }
+
+    pub fn emphasis<E: Into<Emphasis>>(mut self, emphasis: E) -> Self {
+        self.emphasis = Some(emphasis.into());
+        self
+    }
+
+    pub fn children<N: Into<TreemapNode>>(mut self, children: Vec<N>) -> Self {
+        self.children = children.into_iter().map(|n| n.into()).collect();
+        self
+    }
+}
+
+impl From<&str> for TreemapNode {
+    fn from(name: &str) -> Self {
+        Self::new(name)
+    }
+}
+
+impl From<(&str, f64)> for TreemapNode {
+    fn from((name, value): (&str, f64)) -> Self {
+        Self::new(name).value(value)
+    }
+}
+
 #[serde_auto]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -30,11 +416,50 @@ pub struct Treemap {
 
     height: Option<CompositeValue>,
 
+    squarify_ratio: Option<f64>,
+
+    leaf_depth: Option<f64>,
+
+    drill_down_icon: Option<String>,
+
+    roam: Option<bool>,
+
+    node_click: Option<TreemapNodeClick>,
+
+    zoom_to_node_ratio: Option<f64>,
+
+    sort: Option<Sort>,
+
+    color_mapping_by: Option<TreemapColorMappingBy>,
+
+    visible_min: Option<f64>,
+
+    children_visible_min: Option<f64>,
+
     label: Option<Label>,
 
+    upper_label: Option<Label>,
+
     item_style: Option<ItemStyle>,
 
     emphasis: Option<Emphasis>,
+
+    blur: Option<Blur>,
+
+    select: Option<Select>,
+
+    selected_mode: Option<SelectedMode>,
+
+    breadcrumb: Option<TreemapBreadcrumb>,
+
+    levels: Vec<TreemapLevel>,
+
+    #[serde(flatten)]
+    animation: Animation,
+
+    tooltip: Option<Tooltip>,
+
+    data: Vec<TreemapNode>,
 }
 
 impl Treemap {
@@ -50,9 +475,28 @@ impl Treemap {
             bottom: None,
             width: None,
             height: None,
+            squarify_ratio: None,
+            leaf_depth: None,
+            drill_down_icon: None,
+            roam: None,
+            node_click: None,
+            zoom_to_node_ratio: None,
+            sort: None,
+            color_mapping_by: None,
+            visible_min: None,
+            children_visible_min: None,
             label: None,
+            upper_label: None,
             item_style: None,
             emphasis: None,
+            blur: None,
+            select: None,
+            selected_mode: None,
+            breadcrumb: None,
+            levels: vec![],
+            animation: Animation::new(),
+            tooltip: None,
+            data: vec![],
         }
     }
 
@@ -106,11 +550,73 @@ impl Treemap {
         self
     }
 
+    /// The expected aspect ratio of the rectangles.
+    pub fn squarify_ratio<F: Into<f64>>(mut self, squarify_ratio: F) -> Self {
+        self.squarify_ratio = Some(squarify_ratio.into());
+        self
+    }
+
+    /// Number of levels shown at once. Deeper levels are reached by drilling
+    /// down into a node.
+    pub fn leaf_depth<F: Into<f64>>(mut self, leaf_depth: F) -> Self {
+        self.leaf_depth = Some(leaf_depth.into());
+        self
+    }
+
+    /// Marker appended to the label of nodes that can be drilled down into.
+    pub fn drill_down_icon<S: Into<String>>(mut self, drill_down_icon: S) -> Self {
+        self.drill_down_icon = Some(drill_down_icon.into());
+        self
+    }
+
+    pub fn roam(mut self, roam: bool) -> Self {
+        self.roam = Some(roam);
+        self
+    }
+
+    pub fn node_click<N: Into<TreemapNodeClick>>(mut self, node_click: N) -> Self {
+        self.node_click = Some(node_click.into());
+        self
+    }
+
+    /// Ratio of the viewport a node occupies after zooming to it.
+    pub fn zoom_to_node_ratio<F: Into<f64>>(mut self, zoom_to_node_ratio: F) -> Self {
+        self.zoom_to_node_ratio = Some(zoom_to_node_ratio.into());
+        self
+    }
+
+    pub fn sort<S: Into<Sort>>(mut self, sort: S) -> Self {
+        self.sort = Some(sort.into());
+        self
+    }
+
+    pub fn color_mapping_by<C: Into<TreemapColorMappingBy>>(mut self, color_mapping_by: C) -> Self {
+        self.color_mapping_by = Some(color_mapping_by.into());
+        self
+    }
+
+    /// Nodes with an area smaller than this, in square pixels, are hidden.
+    pub fn visible_min<F: Into<f64>>(mut self, visible_min: F) -> Self {
+        self.visible_min = Some(visible_min.into());
+        self
+    }
+
+    pub fn children_visible_min<F: Into<f64>>(mut self, children_visible_min: F) -> Self {
+        self.children_visible_min = Some(children_visible_min.into());
+        self
+    }
+
     pub fn label<L: Into<Label>>(mut self, label: L) -> Self {
         self.label = Some(label.into());
         self
     }
 
+    /// Label shown in the header of nodes that have children.
+    pub fn upper_label<L: Into<Label>>(mut self, upper_label: L) -> Self {
+        self.upper_label = Some(upper_label.into());
+        self
+    }
+
     pub fn item_style<I: Into<ItemStyle>>(mut self, item_style: I) -> Self {
         self.item_style = Some(item_style.into());
         self
@@ -120,4 +626,76 @@ impl Treemap {
         self.emphasis = Some(emphasis.into());
         self
     }
+
+    pub fn blur<B: Into<Blur>>(mut self, blur: B) -> Self {
+        self.blur = Some(blur.into());
+        self
+    }
+
+    pub fn select<S: Into<Select>>(mut self, select: S) -> Self {
+        self.select = Some(select.into());
+        self
+    }
+
+    pub fn selected_mode<S: Into<SelectedMode>>(mut self, selected_mode: S) -> Self {
+        self.selected_mode = Some(selected_mode.into());
+        self
+    }
+
+    pub fn breadcrumb<B: Into<TreemapBreadcrumb>>(mut self, breadcrumb: B) -> Self {
+        self.breadcrumb = Some(breadcrumb.into());
+        self
+    }
+
+    pub fn levels(mut self, levels: Vec<TreemapLevel>) -> Self {
+        self.levels = levels;
+        self
+    }
+
+    pub fn tooltip<T: Into<Tooltip>>(mut self, tooltip: T) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    pub fn data<N: Into<TreemapNode>>(mut self, data: Vec<N>) -> Self {
+        self.data = data.into_iter().map(|n| n.into()).collect();
+        self
+    }
+}
+
+impl_animation!(Treemap);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn treemap_hierarchy() {
+        let treemap = Treemap::new()
+            .leaf_depth(1)
+            .node_click(TreemapNodeClick::None)
+            .breadcrumb(TreemapBreadcrumb::new().show(false))
+            .levels(vec![TreemapLevel::new()
+                .color_mapping_by(TreemapColorMappingBy::Index)
+                .item_style(ItemStyle::new().gap_width(1))])
+            .data(vec![
+                TreemapNode::new("src").children(vec![("lib.rs", 12.0), ("main.rs", 3.0)])
+            ]);
+        assert_eq!(
+            serde_json::to_value(&treemap).unwrap(),
+            serde_json::json!({
+                "leafDepth": 1.0,
+                "nodeClick": false,
+                "breadcrumb": {"show": false},
+                "levels": [{"colorMappingBy": "index", "itemStyle": {"gapWidth": 1.0}}],
+                "data": [{
+                    "name": "src",
+                    "children": [
+                        {"name": "lib.rs", "value": 12.0},
+                        {"name": "main.rs", "value": 3.0},
+                    ],
+                }],
+            })
+        );
+    }
 }
@@ -5,7 +5,7 @@ use charming::{
     },
     element::{
         AxisLine, AxisPointer, AxisPointerLink, AxisPointerType, AxisType, DimensionEncode,
-        SplitArea, SplitLine, Tooltip, Trigger,
+        RenderExpr, RenderGraphic, RenderPoint, SplitArea, SplitLine, Tooltip, Trigger,
     },
     series::Custom,
     Chart,
@@ -87,56 +87,30 @@ pub fn chart() -> Chart {
                         .y(vec![1, 2, 3, 4])
                         .tooltip(vec![1, 2, 3, 4]),
                 )
-                .render_item(RENDER_ITEM)
+                .render_item(render_item())
                 .data(data),
         )
 }
 
-static RENDER_ITEM: &str = r#"
-function (params, api) {
-  var xValue = api.value(0);
-  var openPoint = api.coord([xValue, api.value(1)]);
-  var closePoint = api.coord([xValue, api.value(2)]);
-  var lowPoint = api.coord([xValue, api.value(3)]);
-  var highPoint = api.coord([xValue, api.value(4)]);
-  var halfWidth = api.size([1, 0])[0] * 0.35;
-  var style = api.style({
-    stroke: api.visual('color')
-  });
-  return {
-    type: 'group',
-    children: [
-      {
-        type: 'line',
-        shape: {
-          x1: lowPoint[0],
-          y1: lowPoint[1],
-          x2: highPoint[0],
-          y2: highPoint[1]
-        },
-        style: style
-      },
-      {
-        type: 'line',
-        shape: {
-          x1: openPoint[0],
-          y1: openPoint[1],
-          x2: openPoint[0] - halfWidth,
-          y2: openPoint[1]
-        },
-        style: style
-      },
-      {
-        type: 'line',
-        shape: {
-          x1: closePoint[0],
-          y1: closePoint[1],
-          x2: closePoint[0] + halfWidth,
-          y2: closePoint[1]
-        },
-        style: style
-      }
-    ]
-  };
+fn render_item() -> RenderGraphic {
+    let point = |dim| RenderPoint::coord(RenderExpr::value(0), RenderExpr::value(dim));
+    let (open, close, low, high) = (point(1), point(2), point(3), point(4));
+    let half_width = RenderPoint::size(1, 0).x() * 0.35;
+
+    RenderGraphic::group(
+        vec![
+            RenderGraphic::line(low, high),
+            RenderGraphic::line(
+                open.clone(),
+                RenderPoint::new(open.x() - half_width.clone(), open.y()),
+            ),
+            RenderGraphic::line(
+                close.clone(),
+                RenderPoint::new(close.x() + half_width, close.y()),
+            ),
+        ]
+        .into_iter()
+        .map(|line| line.api_style().stroke(RenderExpr::visual("color")))
+        .collect(),
+    )
 }
-"#;
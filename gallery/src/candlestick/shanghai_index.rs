@@ -2,8 +2,8 @@ use charming::{
     component::{Axis, DataZoom, Grid, Legend},
     datatype::CompositeValue,
     element::{
-        AreaStyle, AxisPointer, AxisPointerType, AxisType, DataBackground, LineStyle, SplitLine,
-        TextStyle, Tooltip, Trigger,
+        AreaStyle, AxisPointer, AxisPointerType, AxisType, DataBackground, ItemStyle, LineStyle,
+        SplitLine, TextStyle, Tooltip, Trigger,
     },
     series::{Candlestick, Line},
     Chart,
@@ -58,7 +58,18 @@ pub fn chart() -> Chart {
                 )
                 .brush_select(true),
         )
-        .series(Candlestick::new().name("Day").data(data.clone()))
+        .series(
+            Candlestick::new()
+                .name("Day")
+                .item_style(
+                    ItemStyle::new()
+                        .color("#FD1050")
+                        .color0("#0CF49B")
+                        .border_color("#FD1050")
+                        .border_color0("#0CF49B"),
+                )
+                .data(data.clone()),
+        )
         .series(
             Line::new()
                 .name("MA5")
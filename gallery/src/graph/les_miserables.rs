@@ -8,7 +8,7 @@ use charming::{
 pub fn chart() -> Chart {
     let mut data: GraphData = serde_json::from_str(include_str!("les-miserables.json")).unwrap();
     for d in data.nodes.iter_mut() {
-        if d.symbol_size > 30.0 {
+        if d.symbol_size.unwrap_or_default() > 30.0 {
             d.label = Some(GraphNodeLabel::new().show(true));
         }
     }
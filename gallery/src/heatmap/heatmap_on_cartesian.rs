@@ -1,7 +1,6 @@
 use charming::{
     component::{Axis, Grid, VisualMap},
-    datatype::{CompositeValue, DataFrame},
-    df,
+    datatype::CompositeValue,
     element::{AxisType, Emphasis, ItemStyle, Label, Orient, SplitArea, Tooltip},
     series::Heatmap,
     Chart,
@@ -178,18 +177,18 @@ pub fn chart() -> Chart {
         vec![6, 22, 2],
         vec![6, 23, 6],
     ];
-    let data: Vec<DataFrame> = data
+    let data: Vec<(i32, i32, CompositeValue)> = data
         .into_iter()
         .map(|d| {
-            df![
+            (
                 d[1],
                 d[0],
                 if d[2] == 0 {
                     CompositeValue::from("-")
                 } else {
                     CompositeValue::from(d[2])
-                }
-            ]
+                },
+            )
         })
         .collect();
 
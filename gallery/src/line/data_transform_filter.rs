@@ -1,6 +1,6 @@
 use charming::{
     component::{Axis, Title},
-    datatype::{CompositeValue, Dataset, Source, Transform},
+    datatype::{Comparison, CompositeValue, Dataset, FilterCondition, Source, Transform},
     element::{AxisType, DimensionEncode, NameLocation, Tooltip, Trigger},
     series::Line,
     Chart,
@@ -15,33 +15,19 @@ pub fn chart() -> Chart {
             Transform::new()
                 .id("dataset_since_1950_of_germany")
                 .from_dataset_id("dataset_raw")
-                .transform(
-                    r#"{
-                        "type": "filter",
-                        "config": {
-                            "and": [
-                                {"dimension": "Year", "gte": 1950},
-                                {"dimension": "Country", "=": "Germany"}
-                            ]
-                        }
-                    }"#,
-                ),
+                .transform(FilterCondition::and(vec![
+                    Comparison::new("Year").gte(1950),
+                    Comparison::new("Country").eq("Germany"),
+                ])),
         )
         .transform(
             Transform::new()
                 .id("dataset_since_1950_of_france")
                 .from_dataset_id("dataset_raw")
-                .transform(
-                    r#"{
-                        "type": "filter",
-                        "config": {
-                            "and": [
-                                {"dimension": "Year", "gte": 1950},
-                                {"dimension": "Country", "=": "France"}
-                            ]
-                        }
-                    }"#,
-                ),
+                .transform(FilterCondition::and(vec![
+                    Comparison::new("Year").gte(1950),
+                    Comparison::new("Country").eq("France"),
+                ])),
         );
 
     Chart::new()
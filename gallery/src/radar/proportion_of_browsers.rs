@@ -1,6 +1,6 @@
 use charming::{
     component::{Legend, LegendType, RadarCoordinate, Title, VisualMap},
-    datatype::{DataPoint, DataPointItem},
+    datatype::DataPointItem,
     element::{AreaStyle, Emphasis, LineStyle, Symbol, Tooltip, Trigger},
     series::Radar,
     Chart,
@@ -14,16 +14,14 @@ pub fn chart() -> Chart {
                 .symbol(Symbol::None)
                 .line_style(LineStyle::new().width(1))
                 .emphasis(Emphasis::new().area_style(AreaStyle::new().color("rgba(0,250,0,0.3)")))
-                .data(vec![DataPoint::Item(
-                    DataPointItem::new(vec![
-                        (40. - i as f64) * 10.,
-                        (38. - i as f64) * 4. + 60.,
-                        i as f64 * 5. + 10.,
-                        i as f64 * 9.,
-                        (i as f64 * i as f64) / 2.,
-                    ])
-                    .name((i + 2000).to_string()),
-                )]),
+                .data(vec![DataPointItem::new(vec![
+                    (40. - i as f64) * 10.,
+                    (38. - i as f64) * 4. + 60.,
+                    i as f64 * 5. + 10.,
+                    i as f64 * 9.,
+                    (i as f64 * i as f64) / 2.,
+                ])
+                .name((i + 2000).to_string())]),
         );
     }
 
@@ -1,7 +1,47 @@
-use charming::{component::Title, element::Tooltip, Chart};
+use charming::{
+    component::Title,
+    element::{ItemStyle, Label, Tooltip},
+    series::{Treemap, TreemapLevel, TreemapNode},
+    Chart,
+};
 
 pub fn chart() -> Chart {
+    let data: Vec<TreemapNode> = serde_json::from_str(include_str!("disk-usage.json")).unwrap();
+
     Chart::new()
         .title(Title::new().text("Disk Usage").left("center"))
         .tooltip(Tooltip::new().formatter("{b}: {c}"))
+        .series(
+            Treemap::new()
+                .name("Disk Usage")
+                .visible_min(300)
+                .label(Label::new().show(true).formatter("{b}"))
+                .upper_label(Label::new().show(true).height(30))
+                .item_style(ItemStyle::new().border_color("#fff"))
+                .levels(vec![
+                    TreemapLevel::new().item_style(
+                        ItemStyle::new()
+                            .border_color("#777")
+                            .border_width(0)
+                            .gap_width(1),
+                    ),
+                    TreemapLevel::new()
+                        .upper_label(Label::new().show(false))
+                        .item_style(
+                            ItemStyle::new()
+                                .border_color("#555")
+                                .border_width(5)
+                                .gap_width(1),
+                        ),
+                    TreemapLevel::new()
+                        .color_saturation((0.35, 0.5))
+                        .item_style(
+                            ItemStyle::new()
+                                .border_width(5)
+                                .gap_width(1)
+                                .border_color_saturation(0.6),
+                        ),
+                ])
+                .data(data),
+        )
 }